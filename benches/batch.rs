@@ -0,0 +1,94 @@
+//! Compares the chunked conversions of `tincture::batch` with calling `tincture::convert` in a loop.
+//!
+//! Run with `cargo bench`.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+use tincture::{LinearRgb, Oklab};
+
+/// The number of colors converted at once, few enough to stay in cache.
+const COLORS: usize = 1 << 12;
+/// The number of times each conversion is timed.
+const RUNS: usize = 1000;
+
+fn colors() -> Vec<LinearRgb> {
+    (0..COLORS)
+        .map(|i| {
+            let n = i as f32 / COLORS as f32;
+            LinearRgb {
+                r: n,
+                g: 1.0 - n,
+                b: (n * 7.0) % 1.0,
+            }
+        })
+        .collect()
+}
+
+/// The fastest of several runs of `f`.
+fn time(mut f: impl FnMut()) -> Duration {
+    f();
+
+    (0..RUNS)
+        .map(|_| {
+            let start = Instant::now();
+            f();
+            start.elapsed()
+        })
+        .min()
+        .unwrap()
+}
+
+fn report(name: &str, baseline: Duration, duration: Duration) {
+    println!(
+        "{:<40} {:>8.2} ns/color {:>6.1}x",
+        name,
+        duration.as_nanos() as f64 / COLORS as f64,
+        baseline.as_secs_f64() / duration.as_secs_f64(),
+    );
+}
+
+fn main() {
+    let rgb = colors();
+    let mut oklab = vec![
+        Oklab {
+            l: 0.0,
+            a: 0.0,
+            b: 0.0
+        };
+        COLORS
+    ];
+    let mut round_trip = rgb.clone();
+
+    let baseline = time(|| {
+        for (color, out) in rgb.iter().zip(&mut oklab) {
+            *out = tincture::convert(black_box(*color));
+        }
+        black_box(&oklab);
+    });
+    report("LinearRgb -> Oklab, convert loop", baseline, baseline);
+    report(
+        "LinearRgb -> Oklab, batch::convert",
+        baseline,
+        time(|| tincture::batch::convert(black_box(&rgb), black_box(&mut oklab))),
+    );
+    report(
+        "LinearRgb -> Oklab, linear_rgb_to_oklab",
+        baseline,
+        time(|| tincture::batch::linear_rgb_to_oklab(black_box(&rgb), black_box(&mut oklab))),
+    );
+
+    let baseline = time(|| {
+        for (color, out) in oklab.iter().zip(&mut round_trip) {
+            *out = tincture::convert(black_box(*color));
+        }
+        black_box(&round_trip);
+    });
+    report("Oklab -> LinearRgb, convert loop", baseline, baseline);
+    report(
+        "Oklab -> LinearRgb, oklab_to_linear_rgb",
+        baseline,
+        time(|| {
+            tincture::batch::oklab_to_linear_rgb(black_box(&oklab), black_box(&mut round_trip))
+        }),
+    );
+}
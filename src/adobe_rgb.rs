@@ -0,0 +1,27 @@
+/// The [Adobe RGB (1998)] color space, for use with [`LinearRgbIn`](crate::LinearRgbIn) and [`EncodedRgbIn`](crate::EncodedRgbIn).
+///
+/// [Adobe RGB (1998)]: https://www.adobe.com/digitalimag/adobergb.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct AdobeRgbSpace;
+
+impl crate::RgbSpaceDefinition for AdobeRgbSpace {
+    type WhitePoint = crate::illuminant::D65;
+
+    const PRIMARIES: [crate::Chromaticity; 3] = [
+        crate::Chromaticity { x: 0.64, y: 0.33 },
+        crate::Chromaticity { x: 0.21, y: 0.71 },
+        crate::Chromaticity { x: 0.15, y: 0.06 },
+    ];
+
+    const TRANSFER: crate::TransferFunction = crate::TransferFunction::Gamma(563.0 / 256.0);
+}
+
+/// A [Adobe RGB (1998)] color.
+///
+/// [Adobe RGB (1998)]: https://www.adobe.com/digitalimag/adobergb.html
+pub type AdobeRgb<T = f32> = crate::EncodedRgbIn<AdobeRgbSpace, T>;
+
+/// A [Adobe RGB (1998)] color without gamma correction.
+///
+/// [Adobe RGB (1998)]: https://www.adobe.com/digitalimag/adobergb.html
+pub type LinearAdobeRgb<T = f32> = crate::LinearRgbIn<AdobeRgbSpace, T>;
@@ -0,0 +1,398 @@
+use crate::{ColorSpace, CoreColorSpace, Float, FromHex, Hex, HueInterpolation, Interpolate};
+
+/// A color with an alpha channel, whose components are not multiplied by alpha (‘straight’ alpha).
+///
+/// Conversions propagate through `Alpha`, leaving alpha unchanged:
+///
+/// ```
+/// use tincture::{Alpha, FromHex, Hex, LinearRgb, Oklab, Oklch, Srgb};
+///
+/// let orange: Alpha<Srgb> = Alpha::from_hex(0xff800080);
+///
+/// let oklab: Alpha<Oklab> = tincture::convert(Alpha::<LinearRgb>::from(orange));
+/// let oklch = Alpha::<Oklch>::from(oklab);
+///
+/// assert_eq!(oklch.alpha, orange.alpha);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Alpha<C, T = f32> {
+    /// The color.
+    pub color: C,
+    /// The opacity of the color (0 to 1).
+    /// 0 is fully transparent, 1 is fully opaque.
+    pub alpha: T,
+}
+
+/// A color with an alpha channel, whose components have been multiplied by alpha.
+///
+/// Premultiplied colors can be interpolated and composited without the color of transparent pixels bleeding through.
+/// See [`Premultiply`] for the color spaces that can be premultiplied.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct PremultipliedAlpha<C, T = f32> {
+    /// The color, with its components multiplied by alpha.
+    pub color: C,
+    /// The opacity of the color (0 to 1).
+    /// 0 is fully transparent, 1 is fully opaque.
+    pub alpha: T,
+}
+
+/// A color whose components can be multiplied by alpha.
+///
+/// As in CSS, every component except hue is multiplied.
+pub trait Premultiply<T = f32>: Sized {
+    /// Multiplies every component except hue by `factor`.
+    fn scale(self, factor: T) -> Self;
+}
+
+impl<C, T> Alpha<C, T> {
+    /// Creates a new color with an alpha channel.
+    pub const fn new(color: C, alpha: T) -> Self {
+        Self { color, alpha }
+    }
+}
+
+impl<C: Premultiply<T>, T: Float> Alpha<C, T> {
+    /// Multiplies the components of the color by alpha.
+    pub fn premultiply(self) -> PremultipliedAlpha<C, T> {
+        PremultipliedAlpha {
+            color: self.color.scale(self.alpha),
+            alpha: self.alpha,
+        }
+    }
+}
+
+impl<C, T> PremultipliedAlpha<C, T> {
+    /// Creates a new color from a color already multiplied by `alpha`.
+    pub const fn new(color: C, alpha: T) -> Self {
+        Self { color, alpha }
+    }
+}
+
+impl<C: Premultiply<T>, T: Float> PremultipliedAlpha<C, T> {
+    /// Divides the components of the color by alpha.
+    ///
+    /// A fully transparent color has lost its components, so it is left unchanged.
+    pub fn unpremultiply(self) -> Alpha<C, T> {
+        let color = if self.alpha == T::ZERO {
+            self.color
+        } else {
+            self.color.scale(T::ONE / self.alpha)
+        };
+
+        Alpha {
+            color,
+            alpha: self.alpha,
+        }
+    }
+}
+
+impl<C: ColorSpace, T: Float> ColorSpace for Alpha<C, T> {
+    const BLACK: Self = Self {
+        color: C::BLACK,
+        alpha: T::ONE,
+    };
+
+    const WHITE: Self = Self {
+        color: C::WHITE,
+        alpha: T::ONE,
+    };
+
+    fn in_bounds(self) -> bool {
+        self.color.in_bounds() && crate::approx_in_range(self.alpha, T::ZERO..T::ONE)
+    }
+}
+
+impl<C: Hex<T>, T: Float> Hex<T> for Alpha<C, T> {
+    fn components(self) -> (T, T, T) {
+        self.color.components()
+    }
+
+    /// Converts the color to a hex value in the form `0xRRGGBBAA`.
+    fn hex(self) -> u32 {
+        (self.color.hex() << 8) | u32::from(crate::hex::to_byte(self.alpha))
+    }
+
+    /// Formats the color as a CSS hex color, like `#ff800080`.
+    ///
+    /// Fully opaque colors leave out alpha, like `#ff8000`.
+    fn hex_string(self) -> String {
+        let hex = self.hex();
+
+        if hex & 0xff == 0xff {
+            format!("#{:06x}", hex >> 8)
+        } else {
+            format!("#{:08x}", hex)
+        }
+    }
+}
+
+impl<C: FromHex<T>, T: Float> FromHex<T> for Alpha<C, T> {
+    fn from_components(components: (T, T, T)) -> Self {
+        Self {
+            color: C::from_components(components),
+            alpha: T::ONE,
+        }
+    }
+
+    /// Creates a color from a hex value in the form `0xRRGGBBAA`.
+    fn from_hex(hex: u32) -> Self {
+        Self {
+            color: C::from_hex(hex >> 8),
+            alpha: crate::hex::from_byte(hex as u8),
+        }
+    }
+
+    /// Parses a color from a hex string of 3, 4, 6 or 8 digits, optionally preceded by `#`,
+    /// as in CSS.
+    ///
+    /// Strings of 3 or 6 digits are fully opaque.
+    ///
+    /// ```
+    /// use tincture::{Alpha, FromHex, Hex, Srgb};
+    ///
+    /// let orange: Alpha<Srgb> = "#ff800080".parse().unwrap();
+    /// assert_eq!(orange.hex(), 0xff800080);
+    /// assert_eq!(Alpha::from_hex_str("f808"), Ok(Alpha::<Srgb>::from_hex(0xff880088)));
+    /// ```
+    fn from_hex_str(s: &str) -> Result<Self, crate::ParseHexError> {
+        let (rgb, alpha) = crate::hex::parse(s)?;
+
+        Ok(Self {
+            color: C::from_hex(rgb),
+            alpha: crate::hex::from_byte(alpha.unwrap_or(u8::MAX)),
+        })
+    }
+}
+
+impl<C: FromHex<T>, T: Float> std::str::FromStr for Alpha<C, T> {
+    type Err = crate::ParseHexError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_hex_str(s)
+    }
+}
+
+impl<In, Out> crate::ConvertInto<Alpha<Out, In::Component>> for Alpha<In, In::Component>
+where
+    In: CoreColorSpace,
+    Out: CoreColorSpace<Component = In::Component>,
+{
+    fn convert_into(self) -> Alpha<Out, In::Component> {
+        Alpha {
+            color: crate::convert(self.color),
+            alpha: self.alpha,
+        }
+    }
+}
+
+impl<In, Out> crate::ConvertInto<PremultipliedAlpha<Out, In::Component>>
+    for PremultipliedAlpha<In, In::Component>
+where
+    In: CoreColorSpace + Premultiply<In::Component>,
+    Out: CoreColorSpace<Component = In::Component> + Premultiply<In::Component>,
+{
+    /// Converts the color without its premultiplication,
+    /// since the conversions of most color spaces are not linear.
+    fn convert_into(self) -> PremultipliedAlpha<Out, In::Component> {
+        let straight: Alpha<Out, In::Component> = crate::convert(self.unpremultiply());
+        straight.premultiply()
+    }
+}
+
+impl<C, T> Interpolate<T> for Alpha<C, T>
+where
+    C: Interpolate<T> + Premultiply<T>,
+    T: Float,
+{
+    /// Interpolates between premultiplied colors, as in CSS,
+    /// so that the color of a transparent color does not show.
+    fn interpolate_with(self, other: Self, t: T, hue: HueInterpolation) -> Self {
+        self.premultiply()
+            .interpolate_with(other.premultiply(), t, hue)
+            .unpremultiply()
+    }
+}
+
+impl<C, T> Interpolate<T> for PremultipliedAlpha<C, T>
+where
+    C: Interpolate<T>,
+    T: Float,
+{
+    fn interpolate_with(self, other: Self, t: T, hue: HueInterpolation) -> Self {
+        Self {
+            color: self.color.interpolate_with(other.color, t, hue),
+            alpha: crate::interpolate::lerp(self.alpha, other.alpha, t),
+        }
+    }
+}
+
+macro_rules! impl_from {
+    ($from:ty => $to:ty, [$($param:ident: $bound:path),*]) => {
+        impl<$($param: $bound,)* T: Float> From<Alpha<$from, T>> for Alpha<$to, T> {
+            fn from(alpha: Alpha<$from, T>) -> Self {
+                Self {
+                    color: <$to>::from(alpha.color),
+                    alpha: alpha.alpha,
+                }
+            }
+        }
+    };
+}
+
+impl_from!(crate::LinearRgb<T> => crate::Srgb<T>, []);
+impl_from!(crate::Srgb<T> => crate::LinearRgb<T>, []);
+impl_from!(crate::Hsl<T> => crate::Srgb<T>, []);
+impl_from!(crate::Srgb<T> => crate::Hsl<T>, []);
+impl_from!(crate::Hsv<T> => crate::Srgb<T>, []);
+impl_from!(crate::Srgb<T> => crate::Hsv<T>, []);
+impl_from!(crate::Hwb<T> => crate::Srgb<T>, []);
+impl_from!(crate::Srgb<T> => crate::Hwb<T>, []);
+impl_from!(crate::Oklch<T> => crate::Oklab<T>, []);
+impl_from!(crate::Oklab<T> => crate::Oklch<T>, []);
+impl_from!(crate::Okhsv<T> => crate::Oklab<T>, []);
+impl_from!(crate::Oklab<T> => crate::Okhsv<T>, []);
+impl_from!(crate::Okhsl<T> => crate::Oklab<T>, []);
+impl_from!(crate::Oklab<T> => crate::Okhsl<T>, []);
+impl_from!(crate::Lch<W, T> => crate::Lab<W, T>, [W: crate::WhitePoint]);
+impl_from!(crate::Lab<W, T> => crate::Lch<W, T>, [W: crate::WhitePoint]);
+impl_from!(crate::Lchuv<W, T> => crate::Luv<W, T>, [W: crate::WhitePoint]);
+impl_from!(crate::Luv<W, T> => crate::Lchuv<W, T>, [W: crate::WhitePoint]);
+impl_from!(crate::EncodedRgbIn<S, T> => crate::LinearRgbIn<S, T>, [S: crate::RgbSpaceDefinition]);
+impl_from!(crate::LinearRgbIn<S, T> => crate::EncodedRgbIn<S, T>, [S: crate::RgbSpaceDefinition]);
+
+macro_rules! impl_premultiply {
+    ($ty:ty, [$($param:ident: $bound:path),*], $($component:ident),*) => {
+        impl<$($param: $bound,)* T: Float> Premultiply<T> for $ty {
+            fn scale(mut self, factor: T) -> Self {
+                $(self.$component *= factor;)*
+                self
+            }
+        }
+    };
+}
+
+impl_premultiply!(crate::Xyz<W, T>, [W: crate::WhitePoint], x, y, z);
+impl_premultiply!(crate::Lab<W, T>, [W: crate::WhitePoint], l, a, b);
+impl_premultiply!(crate::Lch<W, T>, [W: crate::WhitePoint], l, c);
+impl_premultiply!(crate::Luv<W, T>, [W: crate::WhitePoint], l, u, v);
+impl_premultiply!(crate::Lchuv<W, T>, [W: crate::WhitePoint], l, c);
+impl_premultiply!(crate::LinearRgbIn<S, T>, [S: crate::RgbSpaceDefinition], r, g, b);
+impl_premultiply!(crate::EncodedRgbIn<S, T>, [S: crate::RgbSpaceDefinition], r, g, b);
+impl_premultiply!(crate::LinearRgb<T>, [], r, g, b);
+impl_premultiply!(crate::Srgb<T>, [], r, g, b);
+impl_premultiply!(crate::Oklab<T>, [], l, a, b);
+impl_premultiply!(crate::Oklch<T>, [], l, c);
+impl_premultiply!(crate::Hsl<T>, [], s, l);
+impl_premultiply!(crate::Hsv<T>, [], s, v);
+impl_premultiply!(crate::Hwb<T>, [], w, b);
+impl_premultiply!(crate::Okhsv<T>, [], s, v);
+impl_premultiply!(crate::Okhsl<T>, [], s, l);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{LinearRgb, Oklab, Srgb};
+
+    #[test]
+    fn hex() {
+        let color: Alpha<Srgb<f64>, f64> = Alpha::from_hex(0x11223344);
+
+        assert_eq!(color.color.hex(), 0x112233);
+        assert!((color.alpha - f64::from(0x44) / 255.0).abs() < 1e-12);
+        assert_eq!(color.hex(), 0x11223344);
+        assert_eq!(
+            Alpha::<Srgb<f64>, f64>::from_components((1.0, 0.0, 0.0)).hex(),
+            0xff0000ff,
+        );
+    }
+
+    #[test]
+    fn in_bounds_checks_alpha() {
+        assert!(Alpha::new(Srgb::<f64>::WHITE, 0.5).in_bounds());
+        assert!(!Alpha::new(Srgb::<f64>::WHITE, 1.5).in_bounds());
+        assert!(!Alpha::new(Srgb::<f64>::WHITE, -0.5).in_bounds());
+    }
+
+    #[test]
+    fn premultiplied_conversion_round_trips() {
+        let color = Alpha::new(
+            LinearRgb {
+                r: 0.2,
+                g: 0.4,
+                b: 0.8,
+            },
+            0.5,
+        )
+        .premultiply();
+
+        let oklab: PremultipliedAlpha<Oklab<f64>, f64> = crate::convert(color);
+        let back: PremultipliedAlpha<LinearRgb<f64>, f64> = crate::convert(oklab);
+
+        assert!((back.color.r - 0.1).abs() < 1e-9);
+        assert!((back.color.g - 0.2).abs() < 1e-9);
+        assert!((back.color.b - 0.4).abs() < 1e-9);
+        assert_eq!(back.alpha, 0.5);
+    }
+
+    #[test]
+    fn interpolation_is_premultiplied() {
+        let transparent = Alpha::new(Srgb::<f64>::BLACK, 0.0);
+        let white = Alpha::new(Srgb::<f64>::WHITE, 1.0);
+
+        // The color of a transparent stop does not darken the mix.
+        let mixed = transparent.interpolate(white, 0.5);
+        assert_eq!(mixed.color, Srgb::WHITE);
+        assert_eq!(mixed.alpha, 0.5);
+    }
+
+    #[test]
+    fn premultiplies_polar_colors_except_hue() {
+        let hue = crate::Hue::from_degrees(120.0).unwrap();
+        let hsl = Alpha::new(
+            crate::Hsl {
+                h: hue,
+                s: 0.8,
+                l: 0.4,
+            },
+            0.5,
+        )
+        .premultiply();
+        assert_eq!(
+            hsl.color,
+            crate::Hsl {
+                h: hue,
+                s: 0.4,
+                l: 0.2
+            }
+        );
+
+        let hwb = Alpha::new(
+            crate::Hwb {
+                h: hue,
+                w: 0.2,
+                b: 0.6,
+            },
+            0.5,
+        );
+        let mixed = hwb.interpolate(
+            Alpha::new(
+                crate::Hwb {
+                    h: hue,
+                    w: 0.0,
+                    b: 0.0,
+                },
+                0.0,
+            ),
+            0.5,
+        );
+        assert_eq!(
+            mixed.color,
+            crate::Hwb {
+                h: hue,
+                w: 0.2,
+                b: 0.6
+            }
+        );
+        assert_eq!(mixed.alpha, 0.25);
+    }
+}
@@ -0,0 +1,277 @@
+//! Contrast as defined by the [Accessible Perceptual Contrast Algorithm (APCA)][apca],
+//! the contrast method proposed for WCAG 3.
+//!
+//! APCA reports contrast as a lightness contrast (Lc) value from about -108 to 106.
+//! Positive values are dark text on a light background and negative values are light text on a dark background.
+//! Unlike the [WCAG 2 contrast ratio](crate::wcag), the order of the colors matters.
+//!
+//! ```
+//! use tincture::apca::{self, Polarity};
+//! use tincture::Srgb;
+//!
+//! let grey: Srgb = Srgb { r: 0.533, g: 0.533, b: 0.533 };
+//! let white: Srgb = Srgb { r: 1.0, g: 1.0, b: 1.0 };
+//!
+//! let contrast = apca::contrast(grey, white);
+//! assert!((contrast.lc - 63.0).abs() < 0.1);
+//! assert_eq!(contrast.polarity, Polarity::DarkOnLight);
+//!
+//! let contrast = apca::contrast(white, grey);
+//! assert!((contrast.lc + 68.5).abs() < 0.1);
+//! assert_eq!(contrast.polarity, Polarity::LightOnDark);
+//! ```
+//!
+//! This module implements APCA-W3 version 0.0.98G-4g.
+//!
+//! [apca]: https://github.com/Myndex/apca-w3
+
+use crate::Float;
+
+/// A color whose luminance on screen APCA can estimate.
+///
+/// APCA models the luminance of a display rather than the exact luminance of the color space,
+/// so it works on gamma-corrected components.
+pub trait Color: Copy {
+    /// The type of the components of the color.
+    type Component: Float;
+
+    /// The estimated screen luminance of the color, from 0 to 1.
+    fn screen_luminance(self) -> Self::Component;
+}
+
+const TRC: f64 = 2.4;
+
+fn screen_luminance<T: Float>(coefficients: [f64; 3], components: [T; 3]) -> T {
+    let [r, g, b] = components.map(|n| n.max(T::ZERO).powf(T::from_f64(TRC)));
+    let [r_co, g_co, b_co] = coefficients.map(T::from_f64);
+
+    r_co * r + g_co * g + b_co * b
+}
+
+impl<T: Float> Color for crate::Srgb<T> {
+    type Component = T;
+
+    fn screen_luminance(self) -> T {
+        screen_luminance([0.2126729, 0.7151522, 0.0721750], [self.r, self.g, self.b])
+    }
+}
+
+impl<T: Float> Color for crate::DisplayP3<T> {
+    type Component = T;
+
+    fn screen_luminance(self) -> T {
+        screen_luminance(
+            [0.2289829594805780, 0.6917492625852380, 0.0792677779341829],
+            [self.r, self.g, self.b],
+        )
+    }
+}
+
+/// Which of the text and the background is lighter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Polarity {
+    /// Dark text on a light background, which has a positive Lc.
+    DarkOnLight,
+    /// Light text on a dark background, which has a negative Lc.
+    LightOnDark,
+}
+
+/// The APCA contrast of text on a background.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Contrast<T = f32> {
+    /// The lightness contrast, which is 0 when the contrast is too low to measure.
+    pub lc: T,
+    /// The polarity of the text and background.
+    pub polarity: Polarity,
+}
+
+const BLACK_THRESHOLD: f64 = 0.022;
+const BLACK_CLAMP: f64 = 1.414;
+const DELTA_Y_MIN: f64 = 0.0005;
+
+const NORMAL_BACKGROUND: f64 = 0.56;
+const NORMAL_TEXT: f64 = 0.57;
+const REVERSE_BACKGROUND: f64 = 0.65;
+const REVERSE_TEXT: f64 = 0.62;
+
+const SCALE: f64 = 1.14;
+const OFFSET: f64 = 0.027;
+const LOW_CLIP: f64 = 0.1;
+
+/// The APCA contrast of text in the color `text` on `background`.
+pub fn contrast<C: Color>(text: C, background: C) -> Contrast<C::Component> {
+    let f = C::Component::from_f64;
+
+    // Soft-clamp near-black colors, which screens and eyes do not distinguish well.
+    let clamp_black = |y: C::Component| {
+        if y > f(BLACK_THRESHOLD) {
+            y
+        } else {
+            y + (f(BLACK_THRESHOLD) - y).powf(f(BLACK_CLAMP))
+        }
+    };
+
+    let text = clamp_black(text.screen_luminance());
+    let background = clamp_black(background.screen_luminance());
+
+    let polarity = if background > text {
+        Polarity::DarkOnLight
+    } else {
+        Polarity::LightOnDark
+    };
+
+    if (background - text).abs() < f(DELTA_Y_MIN) {
+        return Contrast {
+            lc: C::Component::ZERO,
+            polarity,
+        };
+    }
+
+    let lc = match polarity {
+        Polarity::DarkOnLight => {
+            let sapc =
+                (background.powf(f(NORMAL_BACKGROUND)) - text.powf(f(NORMAL_TEXT))) * f(SCALE);
+
+            if sapc < f(LOW_CLIP) {
+                C::Component::ZERO
+            } else {
+                sapc - f(OFFSET)
+            }
+        }
+        Polarity::LightOnDark => {
+            let sapc =
+                (background.powf(f(REVERSE_BACKGROUND)) - text.powf(f(REVERSE_TEXT))) * f(SCALE);
+
+            if sapc > -f(LOW_CLIP) {
+                C::Component::ZERO
+            } else {
+                sapc + f(OFFSET)
+            }
+        }
+    };
+
+    Contrast {
+        lc: lc * f(100.0),
+        polarity,
+    }
+}
+
+/// What text of a certain weight may be used for at a certain contrast.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub enum Usage {
+    /// The contrast is too low for anything but decorative elements.
+    Prohibited,
+    /// The contrast is only high enough for non-text elements such as icons, or for very large text.
+    NonText,
+    /// Text may be used at or above this size in CSS pixels.
+    Text {
+        /// The minimum font size in CSS pixels.
+        minimum_size: f64,
+    },
+}
+
+const PROHIBITED: f64 = 999.0;
+const NON_TEXT: f64 = 777.0;
+
+/// The minimum font sizes for weights 100 to 900, at Lc values from 0 to 125 in steps of 5.
+#[rustfmt::skip]
+const FONT_SIZES: [[f64; 9]; 26] = [
+    [999.0, 999.0, 999.0, 999.0, 999.0, 999.0, 999.0, 999.0, 999.0],
+    [999.0, 999.0, 999.0, 999.0, 999.0, 999.0, 999.0, 999.0, 999.0],
+    [999.0, 999.0, 999.0, 999.0, 999.0, 999.0, 999.0, 999.0, 999.0],
+    [777.0, 777.0, 777.0, 777.0, 777.0, 777.0, 777.0, 777.0, 777.0],
+    [777.0, 777.0, 777.0, 777.0, 777.0, 777.0, 777.0, 777.0, 777.0],
+    [777.0, 777.0, 777.0, 120.0, 120.0, 108.0, 96.0, 96.0, 96.0],
+    [777.0, 777.0, 120.0, 108.0, 108.0, 96.0, 72.0, 72.0, 72.0],
+    [777.0, 120.0, 108.0, 96.0, 72.0, 60.0, 48.0, 48.0, 48.0],
+    [120.0, 108.0, 96.0, 60.0, 48.0, 42.0, 32.0, 32.0, 32.0],
+    [108.0, 96.0, 72.0, 42.0, 32.0, 28.0, 24.0, 24.0, 24.0],
+    [96.0, 72.0, 60.0, 32.0, 28.0, 24.0, 21.0, 21.0, 21.0],
+    [80.0, 60.0, 48.0, 28.0, 24.0, 21.0, 18.0, 18.0, 18.0],
+    [72.0, 48.0, 42.0, 24.0, 21.0, 18.0, 16.0, 16.0, 18.0],
+    [68.0, 46.0, 32.0, 21.75, 19.0, 17.0, 15.0, 16.0, 18.0],
+    [64.0, 44.0, 28.0, 19.5, 18.0, 16.0, 14.5, 16.0, 18.0],
+    [60.0, 42.0, 24.0, 18.0, 16.0, 15.0, 14.0, 16.0, 18.0],
+    [56.0, 38.25, 23.0, 17.25, 15.81, 14.0, 13.0, 16.0, 18.0],
+    [52.0, 34.5, 22.0, 16.5, 15.625, 13.5, 12.0, 16.0, 18.0],
+    [48.0, 32.0, 21.0, 16.0, 15.5, 13.0, 11.0, 16.0, 18.0],
+    [45.0, 28.0, 19.5, 15.5, 15.0, 12.0, 10.0, 16.0, 18.0],
+    [42.0, 26.5, 18.5, 15.0, 14.5, 11.5, 9.0, 16.0, 18.0],
+    [39.0, 25.0, 18.0, 14.5, 14.0, 11.0, 8.0, 16.0, 18.0],
+    [36.0, 24.0, 18.0, 14.0, 13.0, 10.0, 7.0, 16.0, 18.0],
+    [34.5, 22.5, 17.25, 12.5, 11.875, 9.375, 6.5, 16.0, 18.0],
+    [33.0, 21.0, 16.5, 11.0, 10.75, 8.75, 6.0, 16.0, 18.0],
+    [32.0, 20.0, 16.0, 10.0, 10.0, 8.0, 5.0, 16.0, 18.0],
+];
+
+/// What text with the font weight `weight` (100 to 900, as in CSS) may be used for at the contrast `lc`,
+/// following the APCA font lookup table.
+///
+/// Only the magnitude of `lc` is used.
+/// Contrasts between the rows of the table use the row below,
+/// and weights between the columns of the table use the column below.
+///
+/// ```
+/// use tincture::apca::{self, Usage};
+///
+/// assert_eq!(apca::usage(-75.0, 400), Usage::Text { minimum_size: 18.0 });
+/// assert_eq!(apca::usage(20.0, 700), Usage::NonText);
+/// ```
+pub fn usage<T: Float>(lc: T, weight: u16) -> Usage {
+    let row = ((lc.abs().to_f64() / 5.0) as usize).min(FONT_SIZES.len() - 1);
+    let column = usize::from(weight.clamp(100, 900) / 100 - 1);
+    let size = FONT_SIZES[row][column];
+
+    if size == PROHIBITED {
+        Usage::Prohibited
+    } else if size == NON_TEXT {
+        Usage::NonText
+    } else {
+        Usage::Text { minimum_size: size }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DisplayP3, Srgb};
+
+    fn grey(n: u8) -> Srgb<f64> {
+        let n = f64::from(n) / 255.0;
+        Srgb { r: n, g: n, b: n }
+    }
+
+    #[test]
+    fn reference_values() {
+        let cases = [
+            (0x88, 0xff, 63.056469930209424),
+            (0xff, 0x88, -68.54146436644962),
+            (0x00, 0xaa, 58.146262578561334),
+            (0xaa, 0x00, -56.24113336839742),
+        ];
+
+        for &(text, background, expected) in &cases {
+            let contrast = contrast(grey(text), grey(background));
+            assert!((contrast.lc - expected).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn identical_colors_have_no_contrast() {
+        assert_eq!(contrast(grey(0x80), grey(0x80)).lc, 0.0);
+    }
+
+    #[test]
+    fn display_p3_white_matches_srgb() {
+        let p3: DisplayP3<f64> = DisplayP3::new(1.0, 1.0, 1.0);
+
+        assert!((p3.screen_luminance() - grey(0xff).screen_luminance()).abs() < 1e-6);
+    }
+
+    #[test]
+    fn usage_uses_lower_row_and_column() {
+        assert_eq!(usage(62.0, 450), Usage::Text { minimum_size: 24.0 });
+        assert_eq!(usage(10.0, 400), Usage::Prohibited);
+        assert_eq!(usage(200.0, 900), Usage::Text { minimum_size: 18.0 });
+    }
+}
@@ -0,0 +1,319 @@
+//! Converting many colors at once.
+//!
+//! [`convert`] and [`convert_in_place`] work between any core color spaces,
+//! computing the conversion matrix once for the whole slice.
+//! [`linear_rgb_to_oklab`] and [`oklab_to_linear_rgb`] process colors in fixed-size chunks laid out so that the compiler can vectorize them,
+//! which makes them much faster than calling [`convert`](crate::convert) in a loop for large buffers such as framebuffers.
+//!
+//! ```
+//! use tincture::{LinearRgb, Oklab};
+//!
+//! let pixels = vec![LinearRgb { r: 0.4, g: 0.2, b: 0.6 }; 1024];
+//! let mut oklab = vec![Oklab { l: 0.0, a: 0.0, b: 0.0 }; 1024];
+//!
+//! tincture::batch::linear_rgb_to_oklab(&pixels, &mut oklab);
+//! ```
+
+use crate::converter::Plan;
+use crate::matrix::Matrix;
+use crate::{CoreColorSpace, Float, LinearRgb, Oklab};
+use std::convert::TryInto;
+
+/// The number of colors processed together.
+const CHUNK: usize = 4;
+
+/// Converts the colors in `input` from one color space to another, writing the results to `output`.
+///
+/// # Panics
+///
+/// Panics if `input` and `output` have different lengths.
+pub fn convert<In, Out>(input: &[In], output: &mut [Out])
+where
+    In: CoreColorSpace + Copy,
+    Out: CoreColorSpace<Component = In::Component>,
+{
+    assert_eq!(
+        input.len(),
+        output.len(),
+        "input and output must have the same length",
+    );
+
+    let converter = crate::Converter::new();
+
+    for (color, out) in input.iter().zip(output) {
+        *out = converter.convert(*color);
+    }
+}
+
+/// Converts the colors in `colors` from one color space to another in place,
+/// returning the same memory reinterpreted as the new color space.
+///
+/// This is only possible between color spaces with the same layout,
+/// which is guaranteed by [`Packed`].
+///
+/// ```
+/// use tincture::{LinearRgb, Oklab};
+///
+/// let mut pixels = vec![LinearRgb { r: 1.0, g: 1.0, b: 1.0 }; 4];
+/// let oklab: &mut [Oklab] = tincture::batch::convert_in_place(&mut pixels);
+///
+/// assert!((oklab[0].l - 1.0).abs() < 0.001);
+/// ```
+pub fn convert_in_place<In, Out>(colors: &mut [In]) -> &mut [Out]
+where
+    In: Packed,
+    Out: Packed<Component = In::Component>,
+{
+    let len = colors.len();
+    let ptr = colors.as_mut_ptr();
+
+    for i in 0..len {
+        // SAFETY: `Packed` guarantees that `In` and `Out` are both three `In::Component`s with `repr(C)`,
+        // so every element of `colors` can be read as an `In` and overwritten with an `Out`.
+        unsafe {
+            let color = ptr.add(i).read();
+            ptr.add(i).cast::<Out>().write(crate::convert(color));
+        }
+    }
+
+    // SAFETY: every element has been overwritten with a valid `Out` of the same size and alignment.
+    unsafe { std::slice::from_raw_parts_mut(ptr.cast::<Out>(), len) }
+}
+
+/// Converts the colors in `input` from [`LinearRgb`] to [`Oklab`], writing the results to `output`.
+///
+/// The matrices converting [`LinearRgb`] to XYZ and XYZ to Oklab’s cone responses are fused into one.
+/// Results may differ from those of [`convert`](crate::convert) in the last few bits.
+///
+/// # Panics
+///
+/// Panics if `input` and `output` have different lengths.
+pub fn linear_rgb_to_oklab<T: Float>(input: &[LinearRgb<T>], output: &mut [Oklab<T>]) {
+    chunked(input, output, |rgb| {
+        let [l, m, s] = apply(Plan::<LinearRgb<T>, Oklab<T>>::MATRIX, rgb);
+        let cbrt = |mut n: [T; CHUNK]| {
+            n.iter_mut().for_each(|n| *n = n.fast_cbrt());
+            n
+        };
+
+        apply(crate::oklab::M2, [cbrt(l), cbrt(m), cbrt(s)])
+    });
+}
+
+/// Converts the colors in `input` from [`Oklab`] to [`LinearRgb`], writing the results to `output`.
+///
+/// The matrices converting Oklab’s cone responses to XYZ and XYZ to [`LinearRgb`] are fused into one.
+/// Results may differ from those of [`convert`](crate::convert) in the last few bits.
+///
+/// # Panics
+///
+/// Panics if `input` and `output` have different lengths.
+pub fn oklab_to_linear_rgb<T: Float>(input: &[Oklab<T>], output: &mut [LinearRgb<T>]) {
+    chunked(input, output, |lab| {
+        let [l, m, s] = apply(crate::oklab::M2_INV, lab);
+        let cube = |mut n: [T; CHUNK]| {
+            n.iter_mut().for_each(|n| *n = *n * *n * *n);
+            n
+        };
+
+        apply(
+            Plan::<Oklab<T>, LinearRgb<T>>::MATRIX,
+            [cube(l), cube(m), cube(s)],
+        )
+    });
+}
+
+/// Runs `f` over `input` one chunk at a time,
+/// with the components of each chunk split into separate arrays to allow vectorization.
+#[inline(always)]
+fn chunked<In, Out, T>(
+    input: &[In],
+    output: &mut [Out],
+    f: impl Fn([[T; CHUNK]; 3]) -> [[T; CHUNK]; 3],
+) where
+    In: Packed<Component = T> + Copy,
+    Out: Packed<Component = T>,
+    T: Float,
+{
+    assert_eq!(
+        input.len(),
+        output.len(),
+        "input and output must have the same length",
+    );
+
+    let mut inputs = input.chunks_exact(CHUNK);
+    let mut outputs = output.chunks_exact_mut(CHUNK);
+
+    for (input, output) in (&mut inputs).zip(&mut outputs) {
+        let input: &[In; CHUNK] = input.try_into().unwrap();
+        let output: &mut [Out; CHUNK] = output.try_into().unwrap();
+        *output = chunk(*input, &f);
+    }
+
+    // The last few colors are padded to a whole chunk.
+    let (input, output) = (inputs.remainder(), outputs.into_remainder());
+    if !input.is_empty() {
+        let mut padded = [In::from_components([T::ZERO; 3]); CHUNK];
+        padded[..input.len()].copy_from_slice(input);
+
+        let converted = chunk(padded, &f);
+        for (color, converted) in output.iter_mut().zip(converted) {
+            *color = converted;
+        }
+    }
+}
+
+/// Runs `f` over one chunk of colors, splitting their components into separate arrays.
+#[inline(always)]
+fn chunk<In, Out, T>(
+    input: [In; CHUNK],
+    f: impl Fn([[T; CHUNK]; 3]) -> [[T; CHUNK]; 3],
+) -> [Out; CHUNK]
+where
+    In: Packed<Component = T>,
+    Out: Packed<Component = T>,
+    T: Float,
+{
+    let components = input.map(Packed::to_components);
+    let [c0, c1, c2] = f([0, 1, 2].map(|c| components.map(|color| color[c])));
+
+    std::array::from_fn(|i| Out::from_components([c0[i], c1[i], c2[i]]))
+}
+
+#[inline(always)]
+fn apply<T: Float>(m: Matrix, [a, b, c]: [[T; CHUNK]; 3]) -> [[T; CHUNK]; 3] {
+    let mut result = [[T::ZERO; CHUNK]; 3];
+
+    for (row, out) in m.iter().zip(&mut result) {
+        let [m0, m1, m2] = row.map(T::from_f64);
+        for i in 0..CHUNK {
+            out[i] = a[i] * m0 + b[i] * m1 + c[i] * m2;
+        }
+    }
+
+    result
+}
+
+/// A color that is stored as exactly three components with `repr(C)`,
+/// allowing it to be reinterpreted as any other `Packed` color with the same component type.
+///
+/// This trait is sealed: it is implemented for the core color spaces of this crate and cannot be implemented elsewhere.
+pub trait Packed: CoreColorSpace + sealed::Sealed {
+    #[doc(hidden)]
+    fn to_components(self) -> [Self::Component; 3];
+
+    #[doc(hidden)]
+    fn from_components(components: [Self::Component; 3]) -> Self;
+}
+
+macro_rules! impl_packed {
+    ($ty:ident, [$($param:ident: $bound:path = $example:ty),*], $c0:ident, $c1:ident, $c2:ident, $construct:expr) => {
+        impl<$($param: $bound,)* T: Float> sealed::Sealed for crate::$ty<$($param,)* T> {}
+
+        impl<$($param: $bound,)* T: Float> Packed for crate::$ty<$($param,)* T> {
+            fn to_components(self) -> [T; 3] {
+                [self.$c0, self.$c1, self.$c2]
+            }
+
+            fn from_components([$c0, $c1, $c2]: [T; 3]) -> Self {
+                $construct
+            }
+        }
+
+        assert_packed!(crate::$ty<$($example,)* f32>, $c0, $c1, $c2);
+        assert_packed!(crate::$ty<$($example,)* f64>, $c0, $c1, $c2);
+    };
+}
+
+/// Checks at compile time that `$ty` is laid out as its three components in order,
+/// which [`convert_in_place`] relies on.
+/// Layout does not depend on white points or RGB space definitions, so checking one of each is enough.
+macro_rules! assert_packed {
+    ($ty:ty, $c0:ident, $c1:ident, $c2:ident) => {
+        const _: () = {
+            let size = std::mem::size_of::<<$ty as CoreColorSpace>::Component>();
+            let align = std::mem::align_of::<<$ty as CoreColorSpace>::Component>();
+
+            assert!(std::mem::size_of::<$ty>() == 3 * size);
+            assert!(std::mem::align_of::<$ty>() == align);
+            assert!(std::mem::offset_of!($ty, $c0) == 0);
+            assert!(std::mem::offset_of!($ty, $c1) == size);
+            assert!(std::mem::offset_of!($ty, $c2) == 2 * size);
+        };
+    };
+}
+
+impl_packed!(Xyz, [W: crate::WhitePoint = crate::illuminant::D65], x, y, z, Self::new(x, y, z));
+impl_packed!(Lab, [W: crate::WhitePoint = crate::illuminant::D65], l, a, b, Self::new(l, a, b));
+impl_packed!(Luv, [W: crate::WhitePoint = crate::illuminant::D65], l, u, v, Self::new(l, u, v));
+impl_packed!(LinearRgbIn, [S: crate::RgbSpaceDefinition = crate::DisplayP3Space], r, g, b, Self::new(r, g, b));
+impl_packed!(LinearRgb, [], r, g, b, Self { r, g, b });
+impl_packed!(Oklab, [], l, a, b, Self { l, a, b });
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ColorSpace;
+
+    fn colors() -> Vec<LinearRgb<f32>> {
+        (0..101)
+            .map(|i| {
+                let n = i as f32 / 100.0;
+                LinearRgb {
+                    r: n,
+                    g: 1.0 - n,
+                    b: (n * 7.0) % 1.0,
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn matches_convert() {
+        let input = colors();
+        let mut output = vec![Oklab::BLACK; input.len()];
+        convert(&input, &mut output);
+
+        for (rgb, oklab) in input.iter().zip(&output) {
+            assert_eq!(*oklab, crate::convert(*rgb));
+        }
+    }
+
+    #[test]
+    fn fused_matches_convert() {
+        let input = colors();
+        let mut output = vec![Oklab::BLACK; input.len()];
+        linear_rgb_to_oklab(&input, &mut output);
+
+        for (rgb, oklab) in input.iter().zip(&output) {
+            let expected: Oklab = crate::convert(*rgb);
+            assert!((oklab.l - expected.l).abs() < 1e-5);
+            assert!((oklab.a - expected.a).abs() < 1e-5);
+            assert!((oklab.b - expected.b).abs() < 1e-5);
+        }
+
+        let mut round_trip = vec![LinearRgb::BLACK; input.len()];
+        oklab_to_linear_rgb(&output, &mut round_trip);
+
+        for (original, rgb) in input.iter().zip(&round_trip) {
+            assert!((original.r - rgb.r).abs() < 1e-4);
+            assert!((original.g - rgb.g).abs() < 1e-4);
+            assert!((original.b - rgb.b).abs() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn in_place() {
+        let input = colors();
+        let mut colors = input.clone();
+        let oklab: &mut [Oklab] = convert_in_place(&mut colors);
+
+        for (rgb, oklab) in input.iter().zip(oklab.iter()) {
+            assert_eq!(*oklab, crate::convert(*rgb));
+        }
+    }
+}
@@ -0,0 +1,134 @@
+use crate::matrix::{self, Matrix};
+use std::marker::PhantomData;
+
+/// A chromatic adaptation transform,
+/// which predicts how a color seen under one white point appears under another.
+///
+/// Each transform converts XYZ to a cone response space,
+/// scales the cone responses by the ratio of the two white points
+/// and converts back to XYZ.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ChromaticAdaptation {
+    /// The Bradford transform, used by ICC profiles and CSS.
+    Bradford,
+    /// The transform from the CIECAM02 color appearance model.
+    Cat02,
+    /// The transform from the CAM16 color appearance model.
+    Cat16,
+    /// The von Kries transform using the Hunt-Pointer-Estévez cone response matrix.
+    VonKries,
+}
+
+const BRADFORD: Matrix = [
+    [0.8951, 0.2664, -0.1614],
+    [-0.7502, 1.7135, 0.0367],
+    [0.0389, -0.0685, 1.0296],
+];
+
+const CAT02: Matrix = [
+    [0.7328, 0.4296, -0.1624],
+    [-0.7036, 1.6975, 0.0061],
+    [0.0030, 0.0136, 0.9834],
+];
+
+const CAT16: Matrix = [
+    [0.401288, 0.650173, -0.051461],
+    [-0.250268, 1.204414, 0.045854],
+    [-0.002079, 0.048952, 0.953127],
+];
+
+const VON_KRIES: Matrix = [
+    [0.40024, 0.70760, -0.08081],
+    [-0.22630, 1.16532, 0.04570],
+    [0.0, 0.0, 0.91822],
+];
+
+impl ChromaticAdaptation {
+    const fn cone_response(self) -> Matrix {
+        match self {
+            Self::Bradford => BRADFORD,
+            Self::Cat02 => CAT02,
+            Self::Cat16 => CAT16,
+            Self::VonKries => VON_KRIES,
+        }
+    }
+
+    /// The matrix adapting XYZ values relative to the white point `source` to the white point `destination`.
+    ///
+    /// Both white points are given as XYZ tristimulus values.
+    pub const fn matrix(self, source: [f64; 3], destination: [f64; 3]) -> [[f64; 3]; 3] {
+        let cone_response = self.cone_response();
+        let source_cone = matrix::mul_vector(cone_response, source);
+        let destination_cone = matrix::mul_vector(cone_response, destination);
+
+        let scale = matrix::diagonal([
+            destination_cone[0] / source_cone[0],
+            destination_cone[1] / source_cone[1],
+            destination_cone[2] / source_cone[2],
+        ]);
+
+        matrix::mul(
+            matrix::inverse(cone_response),
+            matrix::mul(scale, cone_response),
+        )
+    }
+
+    /// Adapts a color relative to the white point `S` to the white point `D`.
+    ///
+    /// ```
+    /// use tincture::illuminant::{D50, D65};
+    /// use tincture::{ChromaticAdaptation, ColorSpace, Xyz};
+    ///
+    /// let adapted: Xyz<D50> = ChromaticAdaptation::Cat16.adapt(Xyz::<D65>::WHITE);
+    ///
+    /// assert!((adapted.x - Xyz::<D50>::WHITE.x).abs() < 0.0001);
+    /// assert!((adapted.z - Xyz::<D50>::WHITE.z).abs() < 0.0001);
+    /// ```
+    pub fn adapt<S: crate::WhitePoint, D: crate::WhitePoint, T: crate::Float>(
+        self,
+        xyz: crate::Xyz<S, T>,
+    ) -> crate::Xyz<D, T> {
+        // Adapting between identical white points would only introduce rounding error.
+        if S::XYZ == D::XYZ {
+            return crate::Xyz::new(xyz.x, xyz.y, xyz.z);
+        }
+
+        let m = match self {
+            Self::Bradford => BradfordMatrix::<S, D>::MATRIX,
+            _ => self.matrix(S::XYZ, D::XYZ),
+        };
+
+        let [x, y, z] = matrix::apply(m, [xyz.x, xyz.y, xyz.z]);
+
+        crate::Xyz::new(x, y, z)
+    }
+}
+
+/// The Bradford matrix between two white points, computed at compile time
+/// since it is used for every conversion between spaces with different white points.
+struct BradfordMatrix<S, D>(PhantomData<(S, D)>);
+
+impl<S: crate::WhitePoint, D: crate::WhitePoint> BradfordMatrix<S, D> {
+    const MATRIX: Matrix = ChromaticAdaptation::Bradford.matrix(S::XYZ, D::XYZ);
+}
+
+#[cfg(test)]
+#[test]
+fn bradford_d65_to_d50() {
+    use crate::illuminant::{D50, D65};
+    use crate::WhitePoint;
+
+    let expected = [
+        [1.0478112, 0.0228866, -0.0501270],
+        [0.0295424, 0.9904844, -0.0170491],
+        [-0.0092345, 0.0150436, 0.7521316],
+    ];
+
+    let m = ChromaticAdaptation::Bradford.matrix(D65::XYZ, D50::XYZ);
+
+    for (row, expected_row) in m.iter().zip(&expected) {
+        for (n, expected_n) in row.iter().zip(expected_row) {
+            assert!((n - expected_n).abs() < 0.00001);
+        }
+    }
+}
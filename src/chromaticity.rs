@@ -0,0 +1,18 @@
+/// A chromaticity in the CIE 1931 xy chromaticity diagram.
+///
+/// Chromaticities describe the hue and colorfulness of a color without its luminance,
+/// which makes them the usual way to specify the primaries and white point of a color space.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Chromaticity {
+    /// The proportion of X in X + Y + Z.
+    pub x: f64,
+    /// The proportion of Y in X + Y + Z.
+    pub y: f64,
+}
+
+impl Chromaticity {
+    /// Converts the chromaticity to XYZ tristimulus values with a luminance (Y) of 1.
+    pub const fn to_xyz(self) -> [f64; 3] {
+        [self.x / self.y, 1.0, (1.0 - self.x - self.y) / self.y]
+    }
+}
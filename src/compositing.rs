@@ -0,0 +1,469 @@
+//! Layering colors as in the [W3C Compositing and Blending][compositing] specification,
+//! which is what browsers use for `mix-blend-mode` and `background-blend-mode`.
+//!
+//! A source color is first blended with the backdrop using a [`BlendMode`],
+//! then composited onto it with a Porter-Duff [`Operator`].
+//!
+//! ```
+//! use tincture::compositing::{BlendMode, Compositor};
+//! use tincture::{Alpha, Srgb};
+//!
+//! let red: Alpha<Srgb> = Alpha::new(Srgb { r: 1.0, g: 0.0, b: 0.0 }, 0.5);
+//! let blue: Alpha<Srgb> = Alpha::new(Srgb { r: 0.0, g: 0.0, b: 1.0 }, 1.0);
+//!
+//! let normal = Compositor::default().composite(red, blue);
+//! assert_eq!(normal, Alpha::new(Srgb { r: 0.5, g: 0.0, b: 0.5 }, 1.0));
+//!
+//! let multiply = Compositor {
+//!     blend_mode: BlendMode::Multiply,
+//!     ..Compositor::default()
+//! };
+//! assert_eq!(multiply.composite(red, blue), Alpha::new(Srgb { r: 0.0, g: 0.0, b: 0.5 }, 1.0));
+//! ```
+//!
+//! [compositing]: https://www.w3.org/TR/compositing-1/
+
+use crate::{Alpha, Float, LinearRgb, Srgb};
+
+/// How the colors of the source and the backdrop are mixed where they overlap.
+///
+/// Separable blend modes treat each component on its own,
+/// while the non-separable ones ([`Hue`](BlendMode::Hue), [`Saturation`](BlendMode::Saturation),
+/// [`Color`](BlendMode::Color) and [`Luminosity`](BlendMode::Luminosity)) mix properties of the whole color.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum BlendMode {
+    /// The source color.
+    #[default]
+    Normal,
+    /// The product of the colors, which is darker than either.
+    Multiply,
+    /// The inverse of the product of the inverted colors, which is lighter than either.
+    Screen,
+    /// [`Multiply`](BlendMode::Multiply) or [`Screen`](BlendMode::Screen), depending on the backdrop.
+    Overlay,
+    /// The darker of the colors.
+    Darken,
+    /// The lighter of the colors.
+    Lighten,
+    /// Brightens the backdrop to reflect the source.
+    ColorDodge,
+    /// Darkens the backdrop to reflect the source.
+    ColorBurn,
+    /// [`Multiply`](BlendMode::Multiply) or [`Screen`](BlendMode::Screen), depending on the source.
+    HardLight,
+    /// A softer version of [`HardLight`](BlendMode::HardLight).
+    SoftLight,
+    /// The absolute difference of the colors.
+    Difference,
+    /// Like [`Difference`](BlendMode::Difference) but with lower contrast.
+    Exclusion,
+    /// The hue of the source with the saturation and luminosity of the backdrop.
+    Hue,
+    /// The saturation of the source with the hue and luminosity of the backdrop.
+    Saturation,
+    /// The hue and saturation of the source with the luminosity of the backdrop.
+    Color,
+    /// The luminosity of the source with the hue and saturation of the backdrop.
+    Luminosity,
+}
+
+/// A Porter-Duff compositing operator, which decides how much of the source and of the backdrop remain.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Operator {
+    /// Neither the source nor the backdrop.
+    Clear,
+    /// Only the source.
+    Copy,
+    /// Only the backdrop.
+    Destination,
+    /// The source placed over the backdrop.
+    #[default]
+    SourceOver,
+    /// The backdrop placed over the source.
+    DestinationOver,
+    /// The source where the backdrop is.
+    SourceIn,
+    /// The backdrop where the source is.
+    DestinationIn,
+    /// The source where the backdrop is not.
+    SourceOut,
+    /// The backdrop where the source is not.
+    DestinationOut,
+    /// The source where the backdrop is, over the backdrop.
+    SourceAtop,
+    /// The backdrop where the source is, over the source.
+    DestinationAtop,
+    /// The source where the backdrop is not and the backdrop where the source is not.
+    Xor,
+    /// The sum of the source and the backdrop, clamped to 1.
+    PlusLighter,
+}
+
+impl Operator {
+    /// The fractions of the source and of the backdrop that remain.
+    fn fractions<T: Float>(self, source_alpha: T, backdrop_alpha: T) -> (T, T) {
+        let one = T::ONE;
+        let zero = T::ZERO;
+
+        match self {
+            Self::Clear => (zero, zero),
+            Self::Copy => (one, zero),
+            Self::Destination => (zero, one),
+            Self::SourceOver => (one, one - source_alpha),
+            Self::DestinationOver => (one - backdrop_alpha, one),
+            Self::SourceIn => (backdrop_alpha, zero),
+            Self::DestinationIn => (zero, source_alpha),
+            Self::SourceOut => (one - backdrop_alpha, zero),
+            Self::DestinationOut => (zero, one - source_alpha),
+            Self::SourceAtop => (backdrop_alpha, one - source_alpha),
+            Self::DestinationAtop => (one - backdrop_alpha, source_alpha),
+            Self::Xor => (one - backdrop_alpha, one - source_alpha),
+            Self::PlusLighter => (one, one),
+        }
+    }
+}
+
+/// The color space in which colors are blended and composited.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum WorkingSpace {
+    /// Gamma-corrected [`Srgb`], which is what browsers use.
+    #[default]
+    Srgb,
+    /// [`LinearRgb`], which mixes light physically.
+    LinearRgb,
+}
+
+/// A configuration for compositing a source color onto a backdrop.
+///
+/// The default is the browser default:
+/// [`Normal`](BlendMode::Normal) blending and [`SourceOver`](Operator::SourceOver) compositing in sRGB.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Compositor {
+    /// How the colors are mixed where they overlap.
+    pub blend_mode: BlendMode,
+    /// How much of each color remains.
+    pub operator: Operator,
+    /// The color space colors are blended and composited in.
+    pub space: WorkingSpace,
+}
+
+impl Compositor {
+    /// Composites `source` onto `backdrop`.
+    ///
+    /// The color components and alphas are clamped to 0 to 1 first.
+    pub fn composite<T: Float>(
+        self,
+        source: Alpha<Srgb<T>, T>,
+        backdrop: Alpha<Srgb<T>, T>,
+    ) -> Alpha<Srgb<T>, T> {
+        let (source_color, source_alpha) = self.to_working_space(source);
+        let (backdrop_color, backdrop_alpha) = self.to_working_space(backdrop);
+
+        // Where the backdrop is transparent the source shows unblended.
+        let blended = self.blend_mode.blend(backdrop_color, source_color);
+        let source_color = [0, 1, 2]
+            .map(|i| (T::ONE - backdrop_alpha) * source_color[i] + backdrop_alpha * blended[i]);
+
+        let (source_fraction, backdrop_fraction) =
+            self.operator.fractions(source_alpha, backdrop_alpha);
+
+        let mut alpha = source_alpha * source_fraction + backdrop_alpha * backdrop_fraction;
+        let mut premultiplied = [0, 1, 2].map(|i| {
+            source_alpha * source_fraction * source_color[i]
+                + backdrop_alpha * backdrop_fraction * backdrop_color[i]
+        });
+
+        if self.operator == Operator::PlusLighter {
+            alpha = alpha.min(T::ONE);
+            premultiplied = premultiplied.map(|n| n.min(T::ONE));
+        }
+
+        let color = if alpha == T::ZERO {
+            [T::ZERO; 3]
+        } else {
+            premultiplied.map(|n| n / alpha)
+        };
+
+        self.to_srgb(color, alpha)
+    }
+
+    fn to_working_space<T: Float>(self, color: Alpha<Srgb<T>, T>) -> ([T; 3], T) {
+        let clamp = |n: T| n.clamp(T::ZERO, T::ONE);
+        let srgb = Srgb {
+            r: clamp(color.color.r),
+            g: clamp(color.color.g),
+            b: clamp(color.color.b),
+        };
+
+        let components = match self.space {
+            WorkingSpace::Srgb => [srgb.r, srgb.g, srgb.b],
+            WorkingSpace::LinearRgb => {
+                let linear_rgb = LinearRgb::from(srgb);
+                [linear_rgb.r, linear_rgb.g, linear_rgb.b]
+            }
+        };
+
+        (components, clamp(color.alpha))
+    }
+
+    fn to_srgb<T: Float>(self, [r, g, b]: [T; 3], alpha: T) -> Alpha<Srgb<T>, T> {
+        let color = match self.space {
+            WorkingSpace::Srgb => Srgb { r, g, b },
+            WorkingSpace::LinearRgb => Srgb::from(LinearRgb { r, g, b }),
+        };
+
+        Alpha { color, alpha }
+    }
+}
+
+impl BlendMode {
+    /// The result of blending `source` onto `backdrop`, ignoring alpha.
+    fn blend<T: Float>(self, backdrop: [T; 3], source: [T; 3]) -> [T; 3] {
+        let separable = |f: fn(T, T) -> T| [0, 1, 2].map(|i| f(backdrop[i], source[i]));
+
+        match self {
+            Self::Normal => source,
+            Self::Multiply => separable(multiply),
+            Self::Screen => separable(screen),
+            Self::Overlay => separable(|backdrop, source| hard_light(source, backdrop)),
+            Self::Darken => separable(T::min),
+            Self::Lighten => separable(T::max),
+            Self::ColorDodge => separable(color_dodge),
+            Self::ColorBurn => separable(color_burn),
+            Self::HardLight => separable(hard_light),
+            Self::SoftLight => separable(soft_light),
+            Self::Difference => separable(|backdrop, source| (backdrop - source).abs()),
+            Self::Exclusion => separable(|backdrop, source| {
+                backdrop + source - T::from_f64(2.0) * backdrop * source
+            }),
+            Self::Hue => set_luminosity(
+                set_saturation(source, saturation(backdrop)),
+                luminosity(backdrop),
+            ),
+            Self::Saturation => set_luminosity(
+                set_saturation(backdrop, saturation(source)),
+                luminosity(backdrop),
+            ),
+            Self::Color => set_luminosity(source, luminosity(backdrop)),
+            Self::Luminosity => set_luminosity(backdrop, luminosity(source)),
+        }
+    }
+}
+
+fn multiply<T: Float>(backdrop: T, source: T) -> T {
+    backdrop * source
+}
+
+fn screen<T: Float>(backdrop: T, source: T) -> T {
+    backdrop + source - backdrop * source
+}
+
+fn color_dodge<T: Float>(backdrop: T, source: T) -> T {
+    if backdrop == T::ZERO {
+        T::ZERO
+    } else if source == T::ONE {
+        T::ONE
+    } else {
+        (backdrop / (T::ONE - source)).min(T::ONE)
+    }
+}
+
+fn color_burn<T: Float>(backdrop: T, source: T) -> T {
+    if backdrop == T::ONE {
+        T::ONE
+    } else if source == T::ZERO {
+        T::ZERO
+    } else {
+        T::ONE - ((T::ONE - backdrop) / source).min(T::ONE)
+    }
+}
+
+fn hard_light<T: Float>(backdrop: T, source: T) -> T {
+    let two = T::from_f64(2.0);
+
+    if source <= T::from_f64(0.5) {
+        multiply(backdrop, two * source)
+    } else {
+        screen(backdrop, two * source - T::ONE)
+    }
+}
+
+fn soft_light<T: Float>(backdrop: T, source: T) -> T {
+    let two = T::from_f64(2.0);
+
+    if source <= T::from_f64(0.5) {
+        backdrop - (T::ONE - two * source) * backdrop * (T::ONE - backdrop)
+    } else {
+        let d = if backdrop <= T::from_f64(0.25) {
+            ((T::from_f64(16.0) * backdrop - T::from_f64(12.0)) * backdrop + T::from_f64(4.0))
+                * backdrop
+        } else {
+            backdrop.sqrt()
+        };
+
+        backdrop + (two * source - T::ONE) * (d - backdrop)
+    }
+}
+
+fn luminosity<T: Float>([r, g, b]: [T; 3]) -> T {
+    T::from_f64(0.3) * r + T::from_f64(0.59) * g + T::from_f64(0.11) * b
+}
+
+/// Brings the components of a color whose luminosity has been changed back into 0 to 1,
+/// keeping its luminosity.
+fn clip_color<T: Float>(color: [T; 3]) -> [T; 3] {
+    let l = luminosity(color);
+    let min = color[0].min(color[1]).min(color[2]);
+    let max = color[0].max(color[1]).max(color[2]);
+
+    let mut color = color;
+
+    if min < T::ZERO {
+        color = color.map(|n| l + (n - l) * l / (l - min));
+    }
+
+    if max > T::ONE {
+        color = color.map(|n| l + (n - l) * (T::ONE - l) / (max - l));
+    }
+
+    color
+}
+
+fn set_luminosity<T: Float>(color: [T; 3], l: T) -> [T; 3] {
+    let d = l - luminosity(color);
+    clip_color(color.map(|n| n + d))
+}
+
+fn saturation<T: Float>([r, g, b]: [T; 3]) -> T {
+    r.max(g).max(b) - r.min(g).min(b)
+}
+
+fn set_saturation<T: Float>(color: [T; 3], s: T) -> [T; 3] {
+    let mut indices = [0, 1, 2];
+    indices.sort_by(|&a, &b| color[a].total_cmp(&color[b]));
+    let [min, mid, max] = indices;
+
+    let mut result = [T::ZERO; 3];
+
+    if color[max] > color[min] {
+        result[mid] = (color[mid] - color[min]) * s / (color[max] - color[min]);
+        result[max] = s;
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn color(r: f64, g: f64, b: f64, alpha: f64) -> Alpha<Srgb<f64>, f64> {
+        Alpha::new(Srgb { r, g, b }, alpha)
+    }
+
+    fn compositor(blend_mode: BlendMode, operator: Operator) -> Compositor {
+        Compositor {
+            blend_mode,
+            operator,
+            space: WorkingSpace::Srgb,
+        }
+    }
+
+    fn assert_close(actual: Alpha<Srgb<f64>, f64>, expected: Alpha<Srgb<f64>, f64>) {
+        let actual_components = [actual.color.r, actual.color.g, actual.color.b, actual.alpha];
+        let expected_components = [
+            expected.color.r,
+            expected.color.g,
+            expected.color.b,
+            expected.alpha,
+        ];
+
+        for (a, e) in actual_components.iter().zip(&expected_components) {
+            assert!((a - e).abs() < 1e-9, "{:?} != {:?}", actual, expected);
+        }
+    }
+
+    #[test]
+    fn operators() {
+        let source = color(1.0, 0.0, 0.0, 0.6);
+        let backdrop = color(0.0, 0.0, 1.0, 0.5);
+        let composite =
+            |operator| compositor(BlendMode::Normal, operator).composite(source, backdrop);
+
+        assert_close(composite(Operator::SourceOver), color(0.75, 0.0, 0.25, 0.8));
+        assert_close(composite(Operator::SourceIn), color(1.0, 0.0, 0.0, 0.3));
+        assert_close(composite(Operator::SourceOut), color(1.0, 0.0, 0.0, 0.3));
+        assert_close(composite(Operator::SourceAtop), color(0.6, 0.0, 0.4, 0.5));
+        assert_close(composite(Operator::Xor), color(0.6, 0.0, 0.4, 0.5));
+        assert_close(composite(Operator::PlusLighter), color(0.6, 0.0, 0.5, 1.0));
+        assert_close(composite(Operator::Clear), color(0.0, 0.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn separable_blend_modes() {
+        let backdrop = color(0.2, 0.5, 0.8, 1.0);
+        let source = color(0.4, 0.4, 0.4, 1.0);
+        let blend = |mode| compositor(mode, Operator::SourceOver).composite(source, backdrop);
+
+        assert_close(blend(BlendMode::Multiply), color(0.08, 0.2, 0.32, 1.0));
+        assert_close(blend(BlendMode::Screen), color(0.52, 0.7, 0.88, 1.0));
+        assert_close(blend(BlendMode::Overlay), color(0.16, 0.4, 0.76, 1.0));
+        assert_close(blend(BlendMode::Darken), color(0.2, 0.4, 0.4, 1.0));
+        assert_close(blend(BlendMode::Difference), color(0.2, 0.1, 0.4, 1.0));
+        assert_close(blend(BlendMode::SoftLight), color(0.168, 0.45, 0.768, 1.0));
+    }
+
+    #[test]
+    fn non_separable_blend_modes() {
+        let backdrop = color(0.2, 0.5, 0.8, 1.0);
+        let source = color(0.9, 0.1, 0.1, 1.0);
+        let blend = |mode| {
+            let result = compositor(mode, Operator::SourceOver).composite(source, backdrop);
+            [result.color.r, result.color.g, result.color.b]
+        };
+
+        // Luminosity comes from the backdrop for these modes and from the source for `Luminosity`.
+        for &mode in &[BlendMode::Hue, BlendMode::Saturation, BlendMode::Color] {
+            assert!((luminosity(blend(mode)) - luminosity([0.2, 0.5, 0.8])).abs() < 1e-9);
+        }
+        assert!(
+            (luminosity(blend(BlendMode::Luminosity)) - luminosity([0.9, 0.1, 0.1])).abs() < 1e-9
+        );
+
+        // `Color` keeps the hue and saturation of the source.
+        let [r, g, b] = blend(BlendMode::Color);
+        assert!(r > g && (g - b).abs() < 1e-9);
+    }
+
+    #[test]
+    fn blending_needs_an_opaque_backdrop() {
+        let source = color(0.4, 0.4, 0.4, 1.0);
+        let transparent = color(0.2, 0.5, 0.8, 0.0);
+
+        let result =
+            compositor(BlendMode::Multiply, Operator::SourceOver).composite(source, transparent);
+        assert_close(result, source);
+    }
+
+    #[test]
+    fn linear_working_space() {
+        let white = color(1.0, 1.0, 1.0, 0.5);
+        let black = color(0.0, 0.0, 0.0, 1.0);
+
+        let srgb = Compositor::default().composite(white, black);
+        let linear = Compositor {
+            space: WorkingSpace::LinearRgb,
+            ..Compositor::default()
+        }
+        .composite(white, black);
+
+        assert!((srgb.color.r - 0.5).abs() < 1e-9);
+        assert!((linear.color.r - 0.735).abs() < 0.001);
+    }
+
+    #[test]
+    fn set_saturation_does_not_panic_on_nan() {
+        let result = set_saturation([0.2, f64::NAN, 0.8], 0.5);
+        assert_eq!(result[0], 0.0);
+    }
+}
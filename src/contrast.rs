@@ -0,0 +1,231 @@
+//! Adjusting colors to meet a contrast target.
+//!
+//! ```
+//! use tincture::contrast::{self, Target};
+//! use tincture::{wcag, LinearRgb, Srgb};
+//!
+//! let orange: Srgb = Srgb { r: 1.0, g: 0.5, b: 0.0 };
+//! let white: Srgb = Srgb { r: 1.0, g: 1.0, b: 1.0 };
+//!
+//! let adjusted = contrast::adjust(orange, white, Target::Wcag(4.5)).unwrap();
+//!
+//! let ratio = wcag::contrast_ratio(LinearRgb::from(adjusted), LinearRgb::from(white));
+//! assert!(ratio >= 4.5);
+//! ```
+
+use crate::{Float, LinearRgb, Oklab, Oklch, Srgb};
+
+/// A minimum contrast between two colors.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub enum Target {
+    /// A minimum [WCAG 2 contrast ratio](crate::wcag::contrast_ratio), such as 4.5.
+    Wcag(f64),
+    /// A minimum magnitude of [APCA Lc](crate::apca::contrast), such as 60.
+    Apca(f64),
+}
+
+impl Target {
+    fn is_met<T: Float>(self, foreground: Srgb<T>, background: Srgb<T>) -> bool {
+        match self {
+            Self::Wcag(ratio) => {
+                let foreground = LinearRgb::from(foreground);
+                let background = LinearRgb::from(background);
+
+                crate::wcag::contrast_ratio(foreground, background).to_f64() >= ratio
+            }
+            Self::Apca(lc) => {
+                crate::apca::contrast(foreground, background)
+                    .lc
+                    .abs()
+                    .to_f64()
+                    >= lc
+            }
+        }
+    }
+}
+
+const EPSILON: f64 = 0.00001;
+
+/// The number of steps toward black or white scanned before bisecting.
+const STEPS: usize = 64;
+
+/// Finds the color closest to `foreground` that meets `target` against `background`.
+///
+/// Only the [`Oklch`] lightness of `foreground` is changed,
+/// along with its chroma where the original chroma does not fit in the sRGB gamut at the new lightness.
+/// Both lighter and darker colors are considered, and the one closest to `foreground` in [`Oklab`] is returned.
+///
+/// Returns `foreground` if it already meets `target`,
+/// and `None` if no color of its hue does, not even black or white.
+pub fn adjust<T: Float>(
+    foreground: Srgb<T>,
+    background: Srgb<T>,
+    target: Target,
+) -> Option<Srgb<T>> {
+    if target.is_met(foreground, background) {
+        return Some(foreground);
+    }
+
+    let oklab: Oklab<T> = crate::convert(LinearRgb::from(foreground));
+    let oklch = Oklch::from(oklab);
+
+    let with_lightness = |l: T| with_lightness(oklch, l);
+
+    // Search toward black and toward white for the lightness closest to the original that meets the target.
+    // Contrast is not monotonic in lightness near the cusp, where the chroma that fits in the gamut changes quickly,
+    // so scan in coarse steps for the first lightness that meets the target,
+    // and then bisect between it and the step before.
+    let search = |end: T| {
+        let mut failing = oklch.l;
+
+        for step in 1..=STEPS {
+            let l = oklch.l + (end - oklch.l) * T::from_f64(step as f64 / STEPS as f64);
+
+            if !target.is_met(with_lightness(l), background) {
+                failing = l;
+                continue;
+            }
+
+            let mut passing = l;
+            while (passing - failing).abs() > T::from_f64(EPSILON) {
+                let mid = (passing + failing) / T::from_f64(2.0);
+
+                if target.is_met(with_lightness(mid), background) {
+                    passing = mid;
+                } else {
+                    failing = mid;
+                }
+            }
+
+            return Some(with_lightness(passing));
+        }
+
+        None
+    };
+
+    let distance =
+        |srgb: Srgb<T>| crate::difference::ok(LinearRgb::from(srgb), LinearRgb::from(foreground));
+
+    match (search(T::ZERO), search(T::ONE)) {
+        (Some(darker), Some(lighter)) => {
+            if distance(darker) <= distance(lighter) {
+                Some(darker)
+            } else {
+                Some(lighter)
+            }
+        }
+        (darker, lighter) => darker.or(lighter),
+    }
+}
+
+/// `color` with lightness `l`, with its chroma reduced to fit in the sRGB gamut if necessary.
+fn with_lightness<T: Float>(color: Oklch<T>, l: T) -> Srgb<T> {
+    let c = color.c.min(Oklch::max_srgb_chroma(l, color.h));
+    let linear_rgb: LinearRgb<T> = crate::convert(Oklab::from(Oklch { l, c, h: color.h }));
+
+    // The color is in gamut up to rounding, which clamping removes.
+    let clamp = |n: T| n.clamp(T::ZERO, T::ONE);
+    let srgb = Srgb::from(linear_rgb);
+    Srgb {
+        r: clamp(srgb.r),
+        g: clamp(srgb.g),
+        b: clamp(srgb.b),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn srgb(r: f64, g: f64, b: f64) -> Srgb<f64> {
+        Srgb { r, g, b }
+    }
+
+    fn hue(srgb: Srgb<f64>) -> f64 {
+        let oklab: Oklab<f64> = crate::convert(LinearRgb::from(srgb));
+        Oklch::from(oklab).h.to_degrees()
+    }
+
+    #[test]
+    fn darkens_on_light_background() {
+        let orange = srgb(1.0, 0.5, 0.0);
+        let white = srgb(1.0, 1.0, 1.0);
+
+        let adjusted = adjust(orange, white, Target::Wcag(4.5)).unwrap();
+        let ratio = crate::wcag::contrast_ratio(LinearRgb::from(adjusted), LinearRgb::from(white));
+
+        assert!(ratio >= 4.5);
+        assert!(ratio < 4.51);
+        assert!((hue(adjusted) - hue(orange)).abs() < 1.0);
+    }
+
+    #[test]
+    fn lightens_on_dark_background() {
+        let blue = srgb(0.1, 0.2, 0.6);
+        let black = srgb(0.0, 0.0, 0.0);
+
+        let adjusted = adjust(blue, black, Target::Apca(60.0)).unwrap();
+        let lc = crate::apca::contrast(adjusted, black).lc;
+
+        assert!(lc <= -60.0);
+        assert!(lc > -60.1);
+    }
+
+    #[test]
+    fn keeps_passing_colors() {
+        let black = srgb(0.0, 0.0, 0.0);
+        let white = srgb(1.0, 1.0, 1.0);
+
+        assert_eq!(adjust(black, white, Target::Wcag(7.0)), Some(black));
+    }
+
+    #[test]
+    fn finds_the_closest_color_near_the_cusp() {
+        let distance = |a: Srgb<f64>, b: Srgb<f64>| {
+            crate::difference::ok(LinearRgb::from(a), LinearRgb::from(b))
+        };
+
+        // Saturated brand colors sit at the cusp, where the chroma that fits in the gamut changes fastest.
+        let magenta = srgb(1.0, 0.0, 1.0);
+        let blue = srgb(0.0, 0.0, 1.0);
+        let gold = srgb(1.0, 0.8, 0.0);
+        let backgrounds = [
+            srgb(0.0, 0.0, 0.0),
+            srgb(0.5, 0.5, 0.5),
+            srgb(1.0, 1.0, 1.0),
+        ];
+        let targets = [Target::Wcag(4.5), Target::Apca(46.2), Target::Apca(75.0)];
+
+        for &foreground in &[magenta, blue, gold] {
+            let oklab: Oklab<f64> = crate::convert(LinearRgb::from(foreground));
+            let oklch = Oklch::from(oklab);
+
+            for &background in &backgrounds {
+                for &target in &targets {
+                    let closest = (0..=2000)
+                        .map(|i| with_lightness(oklch, i as f64 / 2000.0))
+                        .filter(|&color| target.is_met(color, background))
+                        .map(|color| distance(color, foreground))
+                        .fold(None, |closest: Option<f64>, d| {
+                            Some(closest.map_or(d, |c| c.min(d)))
+                        });
+
+                    let adjusted = adjust(foreground, background, target);
+                    assert_eq!(adjusted.is_some(), closest.is_some());
+
+                    if let (Some(adjusted), Some(closest)) = (adjusted, closest) {
+                        assert!(target.is_met(adjusted, background));
+                        assert!(distance(adjusted, foreground) <= closest + 0.001);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn reports_impossible_targets() {
+        let grey = srgb(0.5, 0.5, 0.5);
+
+        assert_eq!(adjust(grey, grey, Target::Wcag(22.0)), None);
+    }
+}
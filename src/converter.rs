@@ -0,0 +1,200 @@
+use crate::matrix::{self, Matrix};
+use crate::{ChromaticAdaptation, CoreColorSpace, WhitePoint};
+use std::fmt;
+use std::marker::PhantomData;
+
+/// A reusable conversion from the color space `In` to the color space `Out`.
+///
+/// A `Converter` combines the linear stages of both color spaces
+/// and the chromatic adaptation between their white points into one matrix when it is created,
+/// and stores that matrix in the component type of the colors.
+/// Converting a color then applies a single matrix between the nonlinear steps of the two color spaces.
+///
+/// ```
+/// use tincture::{ChromaticAdaptation, Converter, Lab, LinearRgb};
+/// use tincture::illuminant::D50;
+///
+/// let converter = Converter::<LinearRgb, Lab<D50>>::with_adaptation(ChromaticAdaptation::Cat16);
+///
+/// let lab = converter.convert(LinearRgb { r: 1.0, g: 1.0, b: 1.0 });
+///
+/// assert!((lab.l - 100.0).abs() < 0.001);
+/// assert!(lab.a.abs() < 0.001);
+/// assert!(lab.b.abs() < 0.001);
+/// ```
+pub struct Converter<In: CoreColorSpace, Out> {
+    matrix: [[In::Component; 3]; 3],
+    spaces: PhantomData<fn(In) -> Out>,
+}
+
+impl<In, Out> Converter<In, Out>
+where
+    In: CoreColorSpace,
+    Out: CoreColorSpace<Component = In::Component>,
+{
+    /// Creates a converter that adapts between white points using the Bradford transform,
+    /// producing the same results as [`convert`](crate::convert).
+    pub fn new() -> Self {
+        Self::from_matrix(Plan::<In, Out>::MATRIX)
+    }
+
+    /// Creates a converter that adapts between white points using `adaptation`.
+    pub fn with_adaptation(adaptation: ChromaticAdaptation) -> Self {
+        let adaptation = if same_white_point::<In::WhitePoint, Out::WhitePoint>() {
+            matrix::IDENTITY
+        } else {
+            adaptation.matrix(In::WhitePoint::XYZ, Out::WhitePoint::XYZ)
+        };
+
+        Self::from_matrix(plan::<In, Out>(adaptation))
+    }
+
+    fn from_matrix(m: Matrix) -> Self {
+        Self {
+            matrix: m.map(|row| row.map(crate::Float::from_f64)),
+            spaces: PhantomData,
+        }
+    }
+
+    /// Converts a color from `In` to `Out`.
+    pub fn convert(&self, color: In) -> Out {
+        let [a, b, c] = color.to_linear();
+        let row = |row: [In::Component; 3]| a * row[0] + b * row[1] + c * row[2];
+
+        Out::from_linear([
+            row(self.matrix[0]),
+            row(self.matrix[1]),
+            row(self.matrix[2]),
+        ])
+    }
+}
+
+impl<In, Out> Default for Converter<In, Out>
+where
+    In: CoreColorSpace,
+    Out: CoreColorSpace<Component = In::Component>,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<In: CoreColorSpace, Out> fmt::Debug for Converter<In, Out> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Converter")
+            .field("matrix", &self.matrix)
+            .finish()
+    }
+}
+
+impl<In: CoreColorSpace, Out> Clone for Converter<In, Out> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<In: CoreColorSpace, Out> Copy for Converter<In, Out> {}
+
+/// The combined matrix converting the linear stage of `In` to the linear stage of `Out`
+/// using the Bradford transform, computed at compile time.
+pub(crate) struct Plan<In, Out>(PhantomData<(In, Out)>);
+
+impl<In: CoreColorSpace, Out: CoreColorSpace> Plan<In, Out> {
+    const ADAPTATION: Matrix = if same_white_point::<In::WhitePoint, Out::WhitePoint>() {
+        matrix::IDENTITY
+    } else {
+        ChromaticAdaptation::Bradford.matrix(In::WhitePoint::XYZ, Out::WhitePoint::XYZ)
+    };
+
+    pub(crate) const MATRIX: Matrix = plan::<In, Out>(Self::ADAPTATION);
+}
+
+const fn plan<In: CoreColorSpace, Out: CoreColorSpace>(adaptation: Matrix) -> Matrix {
+    matrix::mul(
+        Out::XYZ_TO_LINEAR,
+        matrix::mul(adaptation, In::LINEAR_TO_XYZ),
+    )
+}
+
+const fn same_white_point<S: WhitePoint, D: WhitePoint>() -> bool {
+    S::XYZ[0] == D::XYZ[0] && S::XYZ[1] == D::XYZ[1] && S::XYZ[2] == D::XYZ[2]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::illuminant::{D50, D65};
+    use crate::{
+        Lab, LinearAdobeRgb, LinearDisplayP3, LinearProPhotoRgb, LinearRec2020, LinearRgb, Luv,
+        Oklab, Xyz,
+    };
+
+    /// Checks that `LINEAR_TO_XYZ` and `to_linear` agree, which `Plan::MATRIX` relies on.
+    fn assert_linear_stage<C: CoreColorSpace<Component = f64> + Copy>(color: C) {
+        let xyz = color.to_xyz();
+        let [x, y, z] = matrix::apply(C::LINEAR_TO_XYZ, color.to_linear());
+
+        assert!((xyz.x - x).abs() < 1e-12);
+        assert!((xyz.y - y).abs() < 1e-12);
+        assert!((xyz.z - z).abs() < 1e-12);
+
+        let back = C::from_linear(matrix::apply(C::XYZ_TO_LINEAR, [x, y, z]));
+        let [x, y, z] = matrix::apply(C::LINEAR_TO_XYZ, back.to_linear());
+
+        assert!((xyz.x - x).abs() < 1e-12);
+        assert!((xyz.y - y).abs() < 1e-12);
+        assert!((xyz.z - z).abs() < 1e-12);
+    }
+
+    #[test]
+    fn linear_stages_match_xyz() {
+        let rgb = (0.9, 0.3, 0.1);
+
+        assert_linear_stage(LinearRgb {
+            r: rgb.0,
+            g: rgb.1,
+            b: rgb.2,
+        });
+        assert_linear_stage(LinearDisplayP3::new(rgb.0, rgb.1, rgb.2));
+        assert_linear_stage(LinearRec2020::new(rgb.0, rgb.1, rgb.2));
+        assert_linear_stage(LinearAdobeRgb::new(rgb.0, rgb.1, rgb.2));
+        assert_linear_stage(LinearProPhotoRgb::new(rgb.0, rgb.1, rgb.2));
+        assert_linear_stage(Oklab {
+            l: 0.7,
+            a: 0.1,
+            b: -0.05,
+        });
+        assert_linear_stage(Lab::<D50, f64>::new(50.0, 20.0, -30.0));
+        assert_linear_stage(Lab::<D65, f64>::new(50.0, 20.0, -30.0));
+        assert_linear_stage(Luv::<D65, f64>::new(50.0, 20.0, -30.0));
+        assert_linear_stage(Xyz::<D50, f64>::new(0.4, 0.5, 0.6));
+    }
+
+    #[test]
+    fn matches_two_step_conversion() {
+        let rgb = LinearRgb {
+            r: 0.4_f64,
+            g: 0.2,
+            b: 0.6,
+        };
+
+        let expected = Oklab::from_xyz(rgb.to_xyz());
+        let oklab: Oklab<f64> = Converter::new().convert(rgb);
+
+        assert!((oklab.l - expected.l).abs() < 1e-12);
+        assert!((oklab.a - expected.a).abs() < 1e-12);
+        assert!((oklab.b - expected.b).abs() < 1e-12);
+    }
+
+    #[test]
+    fn adapts_between_white_points() {
+        let p3 = LinearDisplayP3::new(0.9_f64, 0.3, 0.1);
+
+        let expected = LinearProPhotoRgb::from_xyz(p3.to_xyz().adapt());
+        let pro_photo: LinearProPhotoRgb<f64> = crate::convert(p3);
+
+        assert!((pro_photo.r - expected.r).abs() < 1e-12);
+        assert!((pro_photo.g - expected.g).abs() < 1e-12);
+        assert!((pro_photo.b - expected.b).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,1302 @@
+//! Parsing and serializing colors written in [CSS Color Level 4][css-color-4] syntax.
+//!
+//! [`parse`] accepts every form of color that CSS does:
+//! hex colors, named colors, `transparent`, and the `rgb()`, `rgba()`, `hsl()`, `hsla()`, `hwb()`, `lab()`, `lch()`,
+//! `oklab()`, `oklch()` and `color()` functions, with percentages, angle units and `none` components.
+//! It produces a [`Color`], which keeps the color space the color was written in
+//! and can be converted to any of tincture’s color spaces.
+//! Colors from tincture’s color spaces can be converted to a [`Color`] and serialized with [`Display`](fmt::Display).
+//!
+//! ```
+//! use tincture::css::{self, Space};
+//! use tincture::{Oklab, Srgb};
+//!
+//! let color: css::Color = css::parse("oklch(70% 0.1 120deg / 50%)").unwrap();
+//! assert_eq!(color.space, Space::Oklch);
+//! assert_eq!(color.alpha, Some(0.5));
+//!
+//! let oklab = color.convert::<Oklab>();
+//! assert!((oklab.color.l - 0.7).abs() < 1e-6);
+//!
+//! let rebeccapurple: css::Color = "RebeccaPurple".parse().unwrap();
+//! assert_eq!(rebeccapurple.to_srgb().color, Srgb { r: 0.4, g: 0.2, b: 0.6 });
+//! ```
+//!
+//! Errors point at the part of the input that could not be parsed:
+//!
+//! ```
+//! use tincture::css::{self, ErrorKind};
+//!
+//! let error = css::parse::<f32>("rgb(255 128 0 / 50pt)").unwrap_err();
+//! assert_eq!(error.kind, ErrorKind::UnknownUnit);
+//! assert_eq!(error.span, 16..20);
+//! ```
+//!
+//! [css-color-4]: https://www.w3.org/TR/css-color-4/
+
+use crate::illuminant::{D50, D65};
+use crate::{Alpha, CoreColorSpace, Float, Hue, LinearRgb, ParseHexError, Srgb};
+use std::error::Error;
+use std::fmt;
+use std::ops::Range;
+use std::str::FromStr;
+
+/// A color parsed from CSS, in the color space it was written in.
+///
+/// Components are in the units of the corresponding tincture color space rather than of CSS,
+/// so `rgb(255 0 0)` has a red component of 1, and `lab(50% 0 0)` has a lightness of 50.
+/// Hues are in degrees.
+/// Components written as `none` are `None`; they are treated as 0 when converting,
+/// and take the value of the other color when [interpolating](crate::Interpolate).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Color<T = f32> {
+    /// The color space the color was written in.
+    pub space: Space,
+    /// The components of the color, in the order CSS writes them.
+    pub components: [Option<T>; 3],
+    /// The opacity of the color (0 to 1).
+    pub alpha: Option<T>,
+}
+
+/// A color space that CSS colors can be written in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Space {
+    /// [`Srgb`], from hex colors, named colors and `rgb()`.
+    Srgb,
+    /// [`Hsl`](crate::Hsl), from `hsl()`.
+    Hsl,
+    /// [`Hwb`](crate::Hwb), from `hwb()`.
+    Hwb,
+    /// [`Lab`](crate::Lab) relative to D50, from `lab()`.
+    Lab,
+    /// [`Lch`](crate::Lch) relative to D50, from `lch()`.
+    Lch,
+    /// [`Oklab`](crate::Oklab), from `oklab()`.
+    Oklab,
+    /// [`Oklch`](crate::Oklch), from `oklch()`.
+    Oklch,
+    /// [`Srgb`], from `color(srgb …)`.
+    ///
+    /// Unlike [`Space::Srgb`] it serializes with `color()`, keeping the precision and `none` components of the color.
+    ColorSrgb,
+    /// [`LinearRgb`], from `color(srgb-linear …)`.
+    SrgbLinear,
+    /// [`DisplayP3`](crate::DisplayP3), from `color(display-p3 …)`.
+    DisplayP3,
+    /// [`AdobeRgb`](crate::AdobeRgb), from `color(a98-rgb …)`.
+    A98Rgb,
+    /// [`ProPhotoRgb`](crate::ProPhotoRgb), from `color(prophoto-rgb …)`.
+    ProphotoRgb,
+    /// [`Rec2020`](crate::Rec2020), from `color(rec2020 …)`.
+    Rec2020,
+    /// [`Xyz`](crate::Xyz) relative to D50, from `color(xyz-d50 …)`.
+    XyzD50,
+    /// [`Xyz`](crate::Xyz) relative to D65, from `color(xyz-d65 …)` and `color(xyz …)`.
+    XyzD65,
+}
+
+impl<T: Float> Color<T> {
+    /// Converts the color to the core color space `C`.
+    pub fn convert<C>(self) -> Alpha<C, T>
+    where
+        C: CoreColorSpace<Component = T>,
+    {
+        let [c0, c1, c2] = self.components.map(|c| c.unwrap_or(T::ZERO));
+        let hue = |degrees: T| {
+            Hue::from_degrees(degrees.rem_euclid(T::from_f64(360.0))).unwrap_or(Hue::ZERO)
+        };
+
+        let color = match self.space {
+            Space::Srgb | Space::ColorSrgb | Space::Hsl | Space::Hwb => {
+                crate::convert(LinearRgb::from(self.to_srgb().color))
+            }
+            Space::Lab => crate::convert(crate::Lab::<D50, T>::new(c0, c1, c2)),
+            Space::Lch => {
+                crate::convert(crate::Lab::from(crate::Lch::<D50, T>::new(c0, c1, hue(c2))))
+            }
+            Space::Oklab => crate::convert(crate::Oklab {
+                l: c0,
+                a: c1,
+                b: c2,
+            }),
+            Space::Oklch => crate::convert(crate::Oklab::from(crate::Oklch {
+                l: c0,
+                c: c1,
+                h: hue(c2),
+            })),
+            Space::SrgbLinear => crate::convert(LinearRgb {
+                r: c0,
+                g: c1,
+                b: c2,
+            }),
+            Space::DisplayP3 => crate::convert(crate::LinearDisplayP3::from(
+                crate::DisplayP3::new(c0, c1, c2),
+            )),
+            Space::A98Rgb => crate::convert(crate::LinearAdobeRgb::from(crate::AdobeRgb::new(
+                c0, c1, c2,
+            ))),
+            Space::ProphotoRgb => crate::convert(crate::LinearProPhotoRgb::from(
+                crate::ProPhotoRgb::new(c0, c1, c2),
+            )),
+            Space::Rec2020 => {
+                crate::convert(crate::LinearRec2020::from(crate::Rec2020::new(c0, c1, c2)))
+            }
+            Space::XyzD50 => crate::convert(crate::Xyz::<D50, T>::new(c0, c1, c2)),
+            Space::XyzD65 => crate::convert(crate::Xyz::<D65, T>::new(c0, c1, c2)),
+        };
+
+        Alpha {
+            color,
+            alpha: self.alpha.unwrap_or(T::ZERO),
+        }
+    }
+
+    /// Converts the color to [`Srgb`].
+    ///
+    /// Colors outside the sRGB gamut have components outside 0 to 1.
+    pub fn to_srgb(self) -> Alpha<Srgb<T>, T> {
+        let [c0, c1, c2] = self.components.map(|c| c.unwrap_or(T::ZERO));
+        let hue = |degrees: T| {
+            Hue::from_degrees(degrees.rem_euclid(T::from_f64(360.0))).unwrap_or(Hue::ZERO)
+        };
+
+        let color = match self.space {
+            Space::Srgb | Space::ColorSrgb => Srgb {
+                r: c0,
+                g: c1,
+                b: c2,
+            },
+            Space::Hsl => Srgb::from(crate::Hsl {
+                h: hue(c0),
+                s: c1,
+                l: c2,
+            }),
+            Space::Hwb => Srgb::from(crate::Hwb {
+                h: hue(c0),
+                w: c1,
+                b: c2,
+            }),
+            _ => return self.convert::<LinearRgb<T>>().into(),
+        };
+
+        Alpha {
+            color,
+            alpha: self.alpha.unwrap_or(T::ZERO),
+        }
+    }
+
+    /// Converts the color to the color space `space`, keeping it a CSS color.
+    ///
+    /// As when CSS interpolates colors, a missing component stays missing
+    /// if `space` has an analogous component, like the lightness of `lab()` and `oklch()`
+    /// or the red component of `rgb()` and `color(display-p3 …)`.
+    /// Other missing components are treated as 0.
+    ///
+    /// ```
+    /// use tincture::css::{self, Space};
+    ///
+    /// let color: css::Color = css::parse("lch(none 40 120)").unwrap();
+    /// let oklch = color.to_space(Space::Oklch);
+    ///
+    /// assert_eq!(oklch.space, Space::Oklch);
+    /// assert_eq!(oklch.components[0], None);
+    /// ```
+    pub fn to_space(self, space: Space) -> Self {
+        if space == self.space {
+            return self;
+        }
+
+        let mut color = match space {
+            Space::Srgb => Self::from(self.to_srgb()),
+            Space::ColorSrgb => Self {
+                space,
+                ..Self::from(self.to_srgb())
+            },
+            Space::Hsl => Self::from(Alpha::<crate::Hsl<T>, T>::from(self.to_srgb())),
+            Space::Hwb => Self::from(Alpha::<crate::Hwb<T>, T>::from(self.to_srgb())),
+            Space::Lab => Self::from(self.convert::<crate::Lab<D50, T>>()),
+            Space::Lch => Self::from(Alpha::<crate::Lch<D50, T>, T>::from(
+                self.convert::<crate::Lab<D50, T>>(),
+            )),
+            Space::Oklab => Self::from(self.convert::<crate::Oklab<T>>()),
+            Space::Oklch => Self::from(Alpha::<crate::Oklch<T>, T>::from(
+                self.convert::<crate::Oklab<T>>(),
+            )),
+            Space::SrgbLinear => Self::from(self.convert::<LinearRgb<T>>()),
+            Space::DisplayP3 => Self::from(self.convert::<crate::LinearDisplayP3<T>>()),
+            Space::A98Rgb => Self::from(self.convert::<crate::LinearAdobeRgb<T>>()),
+            Space::ProphotoRgb => Self::from(self.convert::<crate::LinearProPhotoRgb<T>>()),
+            Space::Rec2020 => Self::from(self.convert::<crate::LinearRec2020<T>>()),
+            Space::XyzD50 => Self::from(self.convert::<crate::Xyz<D50, T>>()),
+            Space::XyzD65 => Self::from(self.convert::<crate::Xyz<D65, T>>()),
+        };
+
+        for (index, component) in self.components.iter().enumerate() {
+            let analogous = self.space.analogs()[index]
+                .and_then(|analog| space.analogs().iter().position(|&a| a == Some(analog)));
+
+            if let (None, Some(analogous)) = (component, analogous) {
+                color.components[analogous] = None;
+            }
+        }
+
+        color.alpha = self.alpha;
+        color
+    }
+}
+
+/// A kind of component shared by several color spaces,
+/// which CSS carries forward when it is missing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Analog {
+    Red,
+    Green,
+    Blue,
+    Lightness,
+    Colorfulness,
+    Hue,
+    OpponentA,
+    OpponentB,
+}
+
+impl Space {
+    /// The kinds of the components of the color space,
+    /// from the [table of analogous components](https://www.w3.org/TR/css-color-4/#interpolation-missing).
+    fn analogs(self) -> [Option<Analog>; 3] {
+        use Analog::*;
+
+        match self {
+            Self::Srgb
+            | Self::ColorSrgb
+            | Self::SrgbLinear
+            | Self::DisplayP3
+            | Self::A98Rgb
+            | Self::ProphotoRgb
+            | Self::Rec2020
+            | Self::XyzD50
+            | Self::XyzD65 => [Some(Red), Some(Green), Some(Blue)],
+            Self::Hsl => [Some(Hue), Some(Colorfulness), Some(Lightness)],
+            Self::Hwb => [Some(Hue), None, None],
+            Self::Lab | Self::Oklab => [Some(Lightness), Some(OpponentA), Some(OpponentB)],
+            Self::Lch | Self::Oklch => [Some(Lightness), Some(Colorfulness), Some(Hue)],
+        }
+    }
+
+    /// The index of the hue among the components of the color space, if it has one.
+    pub(crate) fn hue_index(self) -> Option<usize> {
+        self.analogs().iter().position(|&a| a == Some(Analog::Hue))
+    }
+}
+
+impl<T: Float> FromStr for Color<T> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse(s)
+    }
+}
+
+/// The number of decimal places components are serialized with, unless the formatter gives a precision.
+const DEFAULT_PRECISION: usize = 5;
+
+impl<T: Float> fmt::Display for Color<T> {
+    /// Serializes the color as CSS, following [CSS Color Level 4][serializing].
+    ///
+    /// Colors written with the legacy sRGB syntaxes (hex colors, named colors, `rgb()`, `hsl()` and `hwb()`)
+    /// serialize as `rgb()`, or `rgba()` if not fully opaque,
+    /// with components from 0 to 255 rounded to integers.
+    /// Other colors serialize with the function or color space they were written in,
+    /// with components rounded to 5 decimal places and alpha left out if fully opaque.
+    /// Alpha is rounded to 2 decimal places, or 3 if that is needed to keep its 8-bit value.
+    ///
+    /// A precision, like `{:.2}`, sets the number of decimal places of every component including alpha.
+    /// Trailing zeros are always left out.
+    ///
+    /// ```
+    /// use tincture::{css, Alpha, Oklch, Srgb, Hue};
+    ///
+    /// let orange = css::Color::from(Alpha::new(Srgb { r: 1.0, g: 0.5, b: 0.0 }, 0.5));
+    /// assert_eq!(orange.to_string(), "rgba(255, 128, 0, 0.5)");
+    /// assert_eq!(format!("{:.1}", orange), "rgba(255, 127.5, 0, 0.5)");
+    ///
+    /// let hue = Hue::from_degrees(120.0).unwrap();
+    /// let green = css::Color::from(Oklch { l: 0.7, c: 0.123456, h: hue });
+    /// assert_eq!(format!("{:.3}", green), "oklch(0.7 0.123 120)");
+    ///
+    /// let none: css::Color = "color(xyz 0.25 none 0.5 / 20%)".parse().unwrap();
+    /// assert_eq!(none.to_string(), "color(xyz-d65 0.25 none 0.5 / 0.2)");
+    /// ```
+    ///
+    /// [serializing]: https://www.w3.org/TR/css-color-4/#serializing-color-values
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Space::Srgb | Space::Hsl | Space::Hwb = self.space {
+            let Alpha { color, alpha } = self.to_srgb();
+            let precision = f.precision().unwrap_or(0);
+            let channel = |c: T| (c.to_f64() * 255.0).clamp(0.0, 255.0);
+            let opaque = alpha == T::ONE;
+
+            f.write_str(if opaque { "rgb(" } else { "rgba(" })?;
+            write_number(f, channel(color.r), precision)?;
+            f.write_str(", ")?;
+            write_number(f, channel(color.g), precision)?;
+            f.write_str(", ")?;
+            write_number(f, channel(color.b), precision)?;
+
+            if !opaque {
+                f.write_str(", ")?;
+                write_alpha(f, alpha.to_f64(), f.precision())?;
+            }
+
+            return f.write_str(")");
+        }
+
+        let (function, color_space) = match self.space {
+            Space::Lab => ("lab", None),
+            Space::Lch => ("lch", None),
+            Space::Oklab => ("oklab", None),
+            Space::Oklch => ("oklch", None),
+            Space::ColorSrgb => ("color", Some("srgb")),
+            Space::SrgbLinear => ("color", Some("srgb-linear")),
+            Space::DisplayP3 => ("color", Some("display-p3")),
+            Space::A98Rgb => ("color", Some("a98-rgb")),
+            Space::ProphotoRgb => ("color", Some("prophoto-rgb")),
+            Space::Rec2020 => ("color", Some("rec2020")),
+            Space::XyzD50 => ("color", Some("xyz-d50")),
+            Space::XyzD65 => ("color", Some("xyz-d65")),
+            Space::Srgb | Space::Hsl | Space::Hwb => unreachable!(),
+        };
+
+        write!(f, "{}(", function)?;
+        if let Some(color_space) = color_space {
+            write!(f, "{} ", color_space)?;
+        }
+
+        let precision = f.precision().unwrap_or(DEFAULT_PRECISION);
+        for (index, component) in self.components.iter().enumerate() {
+            if index > 0 {
+                f.write_str(" ")?;
+            }
+
+            match component {
+                Some(component) => write_number(f, component.to_f64(), precision)?,
+                None => f.write_str("none")?,
+            }
+        }
+
+        match self.alpha {
+            Some(alpha) if alpha == T::ONE => {}
+            Some(alpha) => {
+                f.write_str(" / ")?;
+                write_alpha(f, alpha.to_f64(), f.precision())?;
+            }
+            None => f.write_str(" / none")?,
+        }
+
+        f.write_str(")")
+    }
+}
+
+/// A private trait for converting the components of tincture’s colors, including hues, to CSS components.
+trait Component<T> {
+    fn value(self) -> T;
+}
+
+impl<T: Float> Component<T> for T {
+    fn value(self) -> T {
+        self
+    }
+}
+
+impl<T: Float> Component<T> for Hue<T> {
+    fn value(self) -> T {
+        self.to_degrees()
+    }
+}
+
+macro_rules! impl_from {
+    ($ty:ty, [$($param:ident),*], $space:ident, $c0:ident, $c1:ident, $c2:ident) => {
+        impl<$($param,)* T: Float> From<$ty> for Color<T> {
+            fn from(color: $ty) -> Self {
+                Self {
+                    space: Space::$space,
+                    components: [
+                        Some(color.$c0.value()),
+                        Some(color.$c1.value()),
+                        Some(color.$c2.value()),
+                    ],
+                    alpha: Some(T::ONE),
+                }
+            }
+        }
+    };
+    ($ty:ty, [$($param:ident),*], via $via:ty) => {
+        impl<$($param,)* T: Float> From<$ty> for Color<T> {
+            fn from(color: $ty) -> Self {
+                Self::from(<$via>::from(color))
+            }
+        }
+    };
+    ($ty:ty, [$($param:ident),*], convert $via:ty) => {
+        impl<$($param,)* T: Float> From<$ty> for Color<T> {
+            fn from(color: $ty) -> Self {
+                Self::from(crate::convert::<_, $via>(color))
+            }
+        }
+    };
+}
+
+impl_from!(Srgb<T>, [], Srgb, r, g, b);
+impl_from!(crate::Hsl<T>, [], Hsl, h, s, l);
+impl_from!(crate::Hwb<T>, [], Hwb, h, w, b);
+impl_from!(crate::Hsv<T>, [], via Srgb<T>);
+impl_from!(LinearRgb<T>, [], SrgbLinear, r, g, b);
+impl_from!(crate::DisplayP3<T>, [], DisplayP3, r, g, b);
+impl_from!(crate::LinearDisplayP3<T>, [], via crate::DisplayP3<T>);
+impl_from!(crate::AdobeRgb<T>, [], A98Rgb, r, g, b);
+impl_from!(crate::LinearAdobeRgb<T>, [], via crate::AdobeRgb<T>);
+impl_from!(crate::ProPhotoRgb<T>, [], ProphotoRgb, r, g, b);
+impl_from!(crate::LinearProPhotoRgb<T>, [], via crate::ProPhotoRgb<T>);
+impl_from!(crate::Rec2020<T>, [], Rec2020, r, g, b);
+impl_from!(crate::LinearRec2020<T>, [], via crate::Rec2020<T>);
+impl_from!(crate::Lab<D50, T>, [], Lab, l, a, b);
+impl_from!(crate::Lab<D65, T>, [], convert crate::Lab<D50, T>);
+impl_from!(crate::Lch<D50, T>, [], Lch, l, c, h);
+impl_from!(crate::Oklab<T>, [], Oklab, l, a, b);
+impl_from!(crate::Oklch<T>, [], Oklch, l, c, h);
+impl_from!(crate::Okhsv<T>, [], via crate::Oklab<T>);
+impl_from!(crate::Okhsl<T>, [], via crate::Oklab<T>);
+impl_from!(crate::Xyz<D50, T>, [], XyzD50, x, y, z);
+impl_from!(crate::Xyz<D65, T>, [], XyzD65, x, y, z);
+impl_from!(crate::Luv<D65, T>, [], convert crate::Xyz<D65, T>);
+impl_from!(crate::Lchuv<D65, T>, [], via crate::Luv<D65, T>);
+
+impl<T: Float> From<crate::Lch<D65, T>> for Color<T> {
+    fn from(color: crate::Lch<D65, T>) -> Self {
+        let lab: crate::Lab<D50, T> = crate::convert(crate::Lab::from(color));
+        Self::from(crate::Lch::from(lab))
+    }
+}
+
+impl<C: Into<Color<T>>, T: Float> From<Alpha<C, T>> for Color<T> {
+    fn from(color: Alpha<C, T>) -> Self {
+        Self {
+            alpha: Some(color.alpha),
+            ..color.color.into()
+        }
+    }
+}
+
+/// An error from parsing a CSS color.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// What went wrong.
+    pub kind: ErrorKind,
+    /// The byte range of the input where it went wrong.
+    pub span: Range<usize>,
+}
+
+/// The kinds of [`ParseError`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorKind {
+    /// The input ended before the color did.
+    UnexpectedEnd,
+    /// The syntax does not allow this here; the string describes what it does allow.
+    Expected(&'static str),
+    /// The name is not a named color.
+    UnknownNamedColor,
+    /// The function is not a color function.
+    UnknownFunction,
+    /// The color space of `color()` is not one of CSS’s predefined color spaces.
+    UnknownColorSpace,
+    /// The unit is not an angle unit.
+    UnknownUnit,
+    /// The hex color is malformed.
+    Hex(ParseHexError),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            ErrorKind::UnexpectedEnd => f.write_str("unexpected end of color")?,
+            ErrorKind::Expected(expected) => write!(f, "expected {}", expected)?,
+            ErrorKind::UnknownNamedColor => f.write_str("unknown named color")?,
+            ErrorKind::UnknownFunction => f.write_str("unknown color function")?,
+            ErrorKind::UnknownColorSpace => f.write_str("unknown color space")?,
+            ErrorKind::UnknownUnit => f.write_str("unknown angle unit")?,
+            ErrorKind::Hex(error) => write!(f, "{}", error)?,
+        }
+
+        write!(f, " at {}..{}", self.span.start, self.span.end)
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parses a CSS color.
+///
+/// Leading and trailing whitespace is allowed, and names and units are case-insensitive.
+pub fn parse<T: Float>(s: &str) -> Result<Color<T>, ParseError> {
+    let mut parser = Parser {
+        tokens: tokenize(s),
+        position: 0,
+        end: s.len(),
+    };
+
+    let color = parser.color()?;
+    parser.end()?;
+
+    Ok(Color {
+        space: color.space,
+        components: color.components.map(|c| c.map(T::from_f64)),
+        alpha: color.alpha.map(T::from_f64),
+    })
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Token<'a> {
+    Ident(&'a str),
+    Function(&'a str),
+    Hash(&'a str),
+    Number(f64),
+    Percentage(f64),
+    Dimension(f64, &'a str),
+    Comma,
+    Slash,
+    CloseParen,
+    Delim(char),
+}
+
+fn is_name_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '-' || c == '_' || !c.is_ascii()
+}
+
+fn tokenize(s: &str) -> Vec<(Token<'_>, Range<usize>)> {
+    let mut tokens = Vec::new();
+    let mut rest = s;
+
+    let take_while = |s: &str, f: fn(char) -> bool| s.find(|c| !f(c)).unwrap_or(s.len());
+
+    loop {
+        rest = rest.trim_start_matches(|c: char| c.is_ascii_whitespace());
+        let start = s.len() - rest.len();
+
+        let c = match rest.chars().next() {
+            Some(c) => c,
+            None => return tokens,
+        };
+
+        let starts_number = |s: &str| {
+            let s = s.strip_prefix(|c| c == '+' || c == '-').unwrap_or(s);
+            let s = s.strip_prefix('.').unwrap_or(s);
+            s.starts_with(|c: char| c.is_ascii_digit())
+        };
+
+        let (token, length) = if c == '#' {
+            let length = 1 + take_while(&rest[1..], is_name_char);
+            (Token::Hash(&rest[1..length]), length)
+        } else if starts_number(rest) {
+            let length = number_length(rest);
+            // The number is made of ASCII digits and signs, so it always parses.
+            let value = rest[..length].parse().unwrap();
+            let after = &rest[length..];
+
+            if after.starts_with('%') {
+                (Token::Percentage(value), length + 1)
+            } else if after.starts_with(|c: char| is_name_char(c) && !c.is_ascii_digit()) {
+                let unit_length = take_while(after, is_name_char);
+                (
+                    Token::Dimension(value, &after[..unit_length]),
+                    length + unit_length,
+                )
+            } else {
+                (Token::Number(value), length)
+            }
+        } else if is_name_char(c) {
+            let length = take_while(rest, is_name_char);
+
+            if rest[length..].starts_with('(') {
+                (Token::Function(&rest[..length]), length + 1)
+            } else {
+                (Token::Ident(&rest[..length]), length)
+            }
+        } else {
+            let token = match c {
+                ',' => Token::Comma,
+                '/' => Token::Slash,
+                ')' => Token::CloseParen,
+                _ => Token::Delim(c),
+            };
+            (token, c.len_utf8())
+        };
+
+        tokens.push((token, start..start + length));
+        rest = &rest[length..];
+    }
+}
+
+/// The length of the number at the start of `s`:
+/// an optional sign, digits with an optional fraction, and an optional exponent.
+fn number_length(s: &str) -> usize {
+    let bytes = s.as_bytes();
+    let digits = |mut i: usize| {
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+        i
+    };
+
+    let mut i = 0;
+    if i < bytes.len() && (bytes[i] == b'+' || bytes[i] == b'-') {
+        i += 1;
+    }
+
+    i = digits(i);
+
+    if i + 1 < bytes.len() && bytes[i] == b'.' && bytes[i + 1].is_ascii_digit() {
+        i = digits(i + 1);
+    }
+
+    if i < bytes.len() && (bytes[i] == b'e' || bytes[i] == b'E') {
+        let mut exponent = i + 1;
+        if exponent < bytes.len() && (bytes[exponent] == b'+' || bytes[exponent] == b'-') {
+            exponent += 1;
+        }
+
+        if exponent < bytes.len() && bytes[exponent].is_ascii_digit() {
+            i = digits(exponent);
+        }
+    }
+
+    i
+}
+
+/// A component value, with angles converted to degrees.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Value {
+    Number(f64),
+    Percentage(f64),
+    Angle(f64),
+    None,
+}
+
+#[derive(Debug, Clone)]
+struct Arguments {
+    values: [(Value, Range<usize>); 3],
+    alpha: Option<(Value, Range<usize>)>,
+    legacy: bool,
+}
+
+struct Parser<'a> {
+    tokens: Vec<(Token<'a>, Range<usize>)>,
+    position: usize,
+    end: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn next(&mut self) -> Result<(Token<'a>, Range<usize>), ParseError> {
+        let token = self.tokens.get(self.position).cloned().ok_or(ParseError {
+            kind: ErrorKind::UnexpectedEnd,
+            span: self.end..self.end,
+        })?;
+
+        self.position += 1;
+        Ok(token)
+    }
+
+    fn peek(&self) -> Option<Token<'a>> {
+        self.tokens.get(self.position).map(|(token, _)| *token)
+    }
+
+    fn expect(&mut self, expected: Token<'_>, description: &'static str) -> Result<(), ParseError> {
+        let (token, span) = self.next()?;
+
+        if token == expected {
+            Ok(())
+        } else {
+            Err(error(ErrorKind::Expected(description), span))
+        }
+    }
+
+    fn end(&mut self) -> Result<(), ParseError> {
+        match self.tokens.get(self.position) {
+            None => Ok(()),
+            Some((_, span)) => Err(error(
+                ErrorKind::Expected("the end of the color"),
+                span.clone(),
+            )),
+        }
+    }
+
+    fn color(&mut self) -> Result<Color<f64>, ParseError> {
+        let (token, span) = self.next()?;
+
+        match token {
+            Token::Hash(hex) => hex_color(hex, span),
+            Token::Ident(name) if name.eq_ignore_ascii_case("transparent") => Ok(Color {
+                space: Space::Srgb,
+                components: [Some(0.0); 3],
+                alpha: Some(0.0),
+            }),
+            Token::Ident(name) => match crate::named::lookup(name) {
+                Some(color) => Ok(srgb_from_hex(crate::Hex::hex(color), 1.0)),
+                None => Err(error(ErrorKind::UnknownNamedColor, span)),
+            },
+            Token::Function(name) => self.function(name, span),
+            _ => Err(error(ErrorKind::Expected("a color"), span)),
+        }
+    }
+
+    fn function(&mut self, name: &str, span: Range<usize>) -> Result<Color<f64>, ParseError> {
+        let (space, legacy_allowed) = match name.to_ascii_lowercase().as_str() {
+            "rgb" | "rgba" => (Space::Srgb, true),
+            "hsl" | "hsla" => (Space::Hsl, true),
+            "hwb" => (Space::Hwb, false),
+            "lab" => (Space::Lab, false),
+            "lch" => (Space::Lch, false),
+            "oklab" => (Space::Oklab, false),
+            "oklch" => (Space::Oklch, false),
+            "color" => return self.color_function(),
+            _ => return Err(error(ErrorKind::UnknownFunction, span)),
+        };
+
+        let arguments = self.arguments(legacy_allowed)?;
+        let [v0, v1, v2] = arguments.values.clone();
+
+        let components = match space {
+            Space::Srgb => {
+                if arguments.legacy {
+                    let is_percentage = matches!(v0.0, Value::Percentage(_));
+
+                    for (value, span) in &[&v1, &v2] {
+                        if matches!(value, Value::Percentage(_)) != is_percentage {
+                            let expected = if is_percentage {
+                                "a percentage, like the first component"
+                            } else {
+                                "a number, like the first component"
+                            };
+                            return Err(error(ErrorKind::Expected(expected), span.clone()));
+                        }
+                    }
+                }
+
+                let rgb = |value| {
+                    number_or_percentage(value, 255.0)
+                        .map(|c| c.map(|c| (c / 255.0).clamp(0.0, 1.0)))
+                };
+                [rgb(v0)?, rgb(v1)?, rgb(v2)?]
+            }
+            Space::Hsl | Space::Hwb => {
+                let fraction = |(value, span): (Value, Range<usize>)| {
+                    if arguments.legacy && matches!(value, Value::Number(_)) {
+                        return Err(error(ErrorKind::Expected("a percentage"), span));
+                    }
+
+                    number_or_percentage((value, span), 100.0)
+                        .map(|c| c.map(|c| (c / 100.0).clamp(0.0, 1.0)))
+                };
+                [hue(v0)?, fraction(v1)?, fraction(v2)?]
+            }
+            Space::Lab => [
+                number_or_percentage(v0, 100.0)?.map(|l| l.clamp(0.0, 100.0)),
+                number_or_percentage(v1, 125.0)?,
+                number_or_percentage(v2, 125.0)?,
+            ],
+            Space::Lch => [
+                number_or_percentage(v0, 100.0)?.map(|l| l.clamp(0.0, 100.0)),
+                number_or_percentage(v1, 150.0)?.map(|c| c.max(0.0)),
+                hue(v2)?,
+            ],
+            Space::Oklab => [
+                number_or_percentage(v0, 1.0)?.map(|l| l.clamp(0.0, 1.0)),
+                number_or_percentage(v1, 0.4)?,
+                number_or_percentage(v2, 0.4)?,
+            ],
+            Space::Oklch => [
+                number_or_percentage(v0, 1.0)?.map(|l| l.clamp(0.0, 1.0)),
+                number_or_percentage(v1, 0.4)?.map(|c| c.max(0.0)),
+                hue(v2)?,
+            ],
+            _ => unreachable!(),
+        };
+
+        Ok(Color {
+            space,
+            components,
+            alpha: alpha(arguments.alpha)?,
+        })
+    }
+
+    fn color_function(&mut self) -> Result<Color<f64>, ParseError> {
+        let (token, span) = self.next()?;
+
+        let space = match token {
+            Token::Ident(name) => match name.to_ascii_lowercase().as_str() {
+                "srgb" => Space::ColorSrgb,
+                "srgb-linear" => Space::SrgbLinear,
+                "display-p3" => Space::DisplayP3,
+                "a98-rgb" => Space::A98Rgb,
+                "prophoto-rgb" => Space::ProphotoRgb,
+                "rec2020" => Space::Rec2020,
+                "xyz-d50" => Space::XyzD50,
+                "xyz" | "xyz-d65" => Space::XyzD65,
+                _ => return Err(error(ErrorKind::UnknownColorSpace, span)),
+            },
+            _ => return Err(error(ErrorKind::Expected("a color space"), span)),
+        };
+
+        let arguments = self.arguments(false)?;
+        let [v0, v1, v2] = arguments.values.clone();
+
+        Ok(Color {
+            space,
+            components: [
+                number_or_percentage(v0, 1.0)?,
+                number_or_percentage(v1, 1.0)?,
+                number_or_percentage(v2, 1.0)?,
+            ],
+            alpha: alpha(arguments.alpha)?,
+        })
+    }
+
+    /// Parses the three components and optional alpha of a color function, and its closing parenthesis.
+    ///
+    /// Components are separated by whitespace and alpha by a slash,
+    /// or if `legacy_allowed`, all of them may be separated by commas instead.
+    fn arguments(&mut self, legacy_allowed: bool) -> Result<Arguments, ParseError> {
+        let first = self.value()?;
+        let legacy = legacy_allowed && self.peek() == Some(Token::Comma);
+
+        let mut values = vec![first];
+        for _ in 0..2 {
+            if legacy {
+                self.expect(Token::Comma, "`,`")?;
+            }
+            values.push(self.value()?);
+        }
+
+        let has_alpha = if legacy {
+            self.peek() == Some(Token::Comma)
+        } else {
+            self.peek() == Some(Token::Slash)
+        };
+
+        let alpha = if has_alpha {
+            self.position += 1;
+            Some(self.value()?)
+        } else {
+            None
+        };
+
+        self.expect(Token::CloseParen, "`)`")?;
+
+        if legacy {
+            for (value, span) in values.iter().chain(&alpha) {
+                if *value == Value::None {
+                    return Err(error(
+                        ErrorKind::Expected(
+                            "a number or percentage, since `none` cannot be used with commas",
+                        ),
+                        span.clone(),
+                    ));
+                }
+            }
+        }
+
+        let mut values = values.into_iter();
+        let mut next = || values.next().unwrap();
+
+        Ok(Arguments {
+            values: [next(), next(), next()],
+            alpha,
+            legacy,
+        })
+    }
+
+    fn value(&mut self) -> Result<(Value, Range<usize>), ParseError> {
+        let (token, span) = self.next()?;
+
+        let value = match token {
+            Token::Number(n) => Value::Number(n),
+            Token::Percentage(p) => Value::Percentage(p),
+            Token::Dimension(n, unit) => {
+                let degrees_per_unit = match unit.to_ascii_lowercase().as_str() {
+                    "deg" => 1.0,
+                    "rad" => 180.0 / std::f64::consts::PI,
+                    "grad" => 0.9,
+                    "turn" => 360.0,
+                    _ => return Err(error(ErrorKind::UnknownUnit, span)),
+                };
+                Value::Angle(n * degrees_per_unit)
+            }
+            Token::Ident(name) if name.eq_ignore_ascii_case("none") => Value::None,
+            _ => {
+                return Err(error(
+                    ErrorKind::Expected("a number, percentage or `none`"),
+                    span,
+                ))
+            }
+        };
+
+        Ok((value, span))
+    }
+}
+
+fn error(kind: ErrorKind, span: Range<usize>) -> ParseError {
+    ParseError { kind, span }
+}
+
+/// A number, or a percentage of `reference`.
+fn number_or_percentage(
+    (value, span): (Value, Range<usize>),
+    reference: f64,
+) -> Result<Option<f64>, ParseError> {
+    match value {
+        Value::Number(n) => Ok(Some(n)),
+        Value::Percentage(p) => Ok(Some(p / 100.0 * reference)),
+        Value::None => Ok(None),
+        Value::Angle(_) => Err(error(ErrorKind::Expected("a number or percentage"), span)),
+    }
+}
+
+/// A hue in degrees from 0 to 360.
+fn hue((value, span): (Value, Range<usize>)) -> Result<Option<f64>, ParseError> {
+    match value {
+        Value::Number(degrees) | Value::Angle(degrees) => Ok(Some(degrees.rem_euclid(360.0))),
+        Value::None => Ok(None),
+        Value::Percentage(_) => Err(error(ErrorKind::Expected("a number or angle"), span)),
+    }
+}
+
+fn alpha(value: Option<(Value, Range<usize>)>) -> Result<Option<f64>, ParseError> {
+    match value {
+        Some(value) => Ok(number_or_percentage(value, 1.0)?.map(|alpha| alpha.clamp(0.0, 1.0))),
+        None => Ok(Some(1.0)),
+    }
+}
+
+/// Writes `n` rounded to `precision` decimal places, without trailing zeros.
+fn write_number(f: &mut fmt::Formatter<'_>, n: f64, precision: usize) -> fmt::Result {
+    let rounded = format!("{:.*}", precision, n);
+    let trimmed = if rounded.contains('.') {
+        rounded.trim_end_matches('0').trim_end_matches('.')
+    } else {
+        &rounded
+    };
+
+    // Negative numbers that round to zero shouldn’t keep their sign.
+    if trimmed == "-0" {
+        f.write_str("0")
+    } else {
+        f.write_str(trimmed)
+    }
+}
+
+/// Writes alpha with `precision` decimal places,
+/// or else the fewest of 2 or 3 that keep its 8-bit value.
+fn write_alpha(f: &mut fmt::Formatter<'_>, alpha: f64, precision: Option<usize>) -> fmt::Result {
+    let alpha = alpha.clamp(0.0, 1.0);
+    let precision = precision.unwrap_or_else(|| {
+        let byte = (alpha * 255.0).round();
+        let two_places = (alpha * 100.0).round() / 100.0;
+
+        if (two_places * 255.0).round() == byte {
+            2
+        } else {
+            3
+        }
+    });
+
+    write_number(f, alpha, precision)
+}
+
+fn srgb_from_hex(rgb: u32, alpha: f64) -> Color<f64> {
+    let byte = |shift: u32| Some(f64::from((rgb >> shift) as u8) / 255.0);
+
+    Color {
+        space: Space::Srgb,
+        components: [byte(16), byte(8), byte(0)],
+        alpha: Some(alpha),
+    }
+}
+
+fn hex_color(hex: &str, span: Range<usize>) -> Result<Color<f64>, ParseError> {
+    match crate::hex::parse(hex) {
+        Ok((rgb, alpha)) => Ok(srgb_from_hex(
+            rgb,
+            alpha.map_or(1.0, |alpha| f64::from(alpha) / 255.0),
+        )),
+        Err(hex_error) => {
+            // Point at the offending digit if there is one, or else at the whole color.
+            let span = match hex_error {
+                ParseHexError::InvalidDigit { character, index } => {
+                    let start = span.start + 1 + index;
+                    start..start + character.len_utf8()
+                }
+                _ => span,
+            };
+
+            Err(error(ErrorKind::Hex(hex_error), span))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(s: &str) -> Result<Color<f64>, ParseError> {
+        super::parse(s)
+    }
+
+    fn color(space: Space, components: [f64; 3], alpha: f64) -> Color<f64> {
+        Color {
+            space,
+            components: components.map(Some),
+            alpha: Some(alpha),
+        }
+    }
+
+    fn assert_close(actual: Color<f64>, expected: Color<f64>) {
+        assert_eq!(actual.space, expected.space);
+
+        let actual_values = actual
+            .components
+            .iter()
+            .chain(&[actual.alpha])
+            .cloned()
+            .collect::<Vec<_>>();
+        let expected_values = expected
+            .components
+            .iter()
+            .chain(&[expected.alpha])
+            .cloned()
+            .collect::<Vec<_>>();
+
+        for (a, e) in actual_values.iter().zip(&expected_values) {
+            match (a, e) {
+                (Some(a), Some(e)) => {
+                    assert!((a - e).abs() < 1e-9, "{:?} != {:?}", actual, expected)
+                }
+                _ => assert_eq!(a, e, "{:?} != {:?}", actual, expected),
+            }
+        }
+    }
+
+    #[test]
+    fn rgb() {
+        let orange = color(Space::Srgb, [1.0, 0.5, 0.0], 1.0);
+        let translucent = color(Space::Srgb, [1.0, 0.5, 0.0], 0.5);
+
+        assert_close(parse("rgb(255 127.5 0)").unwrap(), orange);
+        assert_close(parse("RGB(100% 50% 0%)").unwrap(), orange);
+        assert_close(parse("rgb(255, 127.5, 0)").unwrap(), orange);
+        assert_close(parse("rgba(255,127.5,0,0.5)").unwrap(), translucent);
+        assert_close(parse("rgb(255 127.5 0 / 50%)").unwrap(), translucent);
+        assert_close(parse("rgb(300 127.5 -10)").unwrap(), orange);
+        assert_close(parse("  rgb( 100% 50% 0 )  ").unwrap(), orange);
+    }
+
+    #[test]
+    fn hex_and_named() {
+        assert_close(
+            parse("#f80").unwrap(),
+            color(Space::Srgb, [1.0, 0.53333333333, 0.0], 1.0),
+        );
+        assert_close(
+            parse("#ff000080").unwrap(),
+            color(Space::Srgb, [1.0, 0.0, 0.0], 128.0 / 255.0),
+        );
+        assert_close(
+            parse("Red").unwrap(),
+            color(Space::Srgb, [1.0, 0.0, 0.0], 1.0),
+        );
+        assert_close(
+            parse("transparent").unwrap(),
+            color(Space::Srgb, [0.0, 0.0, 0.0], 0.0),
+        );
+    }
+
+    #[test]
+    fn other_functions() {
+        assert_close(
+            parse("hsl(120deg 100% 25%)").unwrap(),
+            color(Space::Hsl, [120.0, 1.0, 0.25], 1.0),
+        );
+        assert_close(
+            parse("hsla(0.5turn, 50%, 50%, 1)").unwrap(),
+            color(Space::Hsl, [180.0, 0.5, 0.5], 1.0),
+        );
+        assert_close(
+            parse("hwb(-90 10 20)").unwrap(),
+            color(Space::Hwb, [270.0, 0.1, 0.2], 1.0),
+        );
+        assert_close(
+            parse("lab(50% 100% -50%)").unwrap(),
+            color(Space::Lab, [50.0, 125.0, -62.5], 1.0),
+        );
+        assert_close(
+            parse("lch(50 75 3.14159265358979rad)").unwrap(),
+            color(Space::Lch, [50.0, 75.0, 180.0], 1.0),
+        );
+        assert_close(
+            parse("oklab(0.5 -0.1 10%)").unwrap(),
+            color(Space::Oklab, [0.5, -0.1, 0.04], 1.0),
+        );
+        assert_close(
+            parse("oklch(50% 0.2 100grad)").unwrap(),
+            color(Space::Oklch, [0.5, 0.2, 90.0], 1.0),
+        );
+        assert_close(
+            parse("color(display-p3 1 0.5 0 / 0.25)").unwrap(),
+            color(Space::DisplayP3, [1.0, 0.5, 0.0], 0.25),
+        );
+        assert_close(
+            parse("color(xyz 0.5 1e-1 -0.1)").unwrap(),
+            color(Space::XyzD65, [0.5, 0.1, -0.1], 1.0),
+        );
+    }
+
+    #[test]
+    fn none() {
+        let color = parse("oklch(0.5 none none / none)").unwrap();
+
+        assert_eq!(color.components, [Some(0.5), None, None]);
+        assert_eq!(color.alpha, None);
+    }
+
+    #[test]
+    fn errors() {
+        let error = |s: &str| {
+            let error = parse(s).unwrap_err();
+            (error.kind, error.span)
+        };
+
+        assert_eq!(error(""), (ErrorKind::UnexpectedEnd, 0..0));
+        assert_eq!(error("rgb(1 2"), (ErrorKind::UnexpectedEnd, 7..7));
+        assert_eq!(error("rgb(1, 2 3)"), (ErrorKind::Expected("`,`"), 9..10));
+        assert_eq!(error("rgb(1, 2%, 3)").1, 7..9);
+        assert_eq!(error("rgb(1, none, 3)").1, 7..11);
+        assert_eq!(error("hsl(10% 50% 50%)").1, 4..7);
+        assert_eq!(error("foo(1 2 3)"), (ErrorKind::UnknownFunction, 0..4));
+        assert_eq!(
+            error("color(p3 1 2 3)"),
+            (ErrorKind::UnknownColorSpace, 6..8)
+        );
+        assert_eq!(error("notacolor"), (ErrorKind::UnknownNamedColor, 0..9));
+        assert_eq!(
+            error("red blue"),
+            (ErrorKind::Expected("the end of the color"), 4..8)
+        );
+        assert_eq!(
+            error("#ff0g00"),
+            (
+                ErrorKind::Hex(ParseHexError::InvalidDigit {
+                    character: 'g',
+                    index: 3,
+                }),
+                4..5,
+            ),
+        );
+
+        assert_eq!(
+            parse("lab(50 0 0deg)").unwrap_err().to_string(),
+            "expected a number or percentage at 9..13",
+        );
+    }
+
+    #[test]
+    fn serializes() {
+        let serialize = |s: &str| parse(s).unwrap().to_string();
+
+        assert_eq!(serialize("#ff8000"), "rgb(255, 128, 0)");
+        assert_eq!(serialize("#ff800081"), "rgba(255, 128, 0, 0.506)");
+        assert_eq!(
+            serialize("hsl(120 100% 25% / 0.25)"),
+            "rgba(0, 128, 0, 0.25)"
+        );
+        assert_eq!(serialize("rgb(none 300 -5 / none)"), "rgba(0, 255, 0, 0)");
+        assert_eq!(serialize("lab(50% -0.000001 1e-7)"), "lab(50 0 0)");
+        assert_eq!(serialize("lch(50 30 none / 10%)"), "lch(50 30 none / 0.1)");
+        assert_eq!(
+            serialize("oklab(0.123456789 0.1 -0.1)"),
+            "oklab(0.12346 0.1 -0.1)"
+        );
+        assert_eq!(
+            serialize("color(srgb 0.5 0.5 0.5)"),
+            "color(srgb 0.5 0.5 0.5)"
+        );
+        assert_eq!(
+            serialize("color(srgb 0.123456 none 1.5 / 0.5)"),
+            "color(srgb 0.12346 none 1.5 / 0.5)"
+        );
+        assert_eq!(
+            serialize("color(rec2020 1 0.5 0 / 1)"),
+            "color(rec2020 1 0.5 0)"
+        );
+
+        let color: Color<f64> =
+            Alpha::new(crate::Xyz::<D65, f64>::new(0.25, 0.5, 1.0), 0.75).into();
+        assert_eq!(format!("{:.1}", color), "color(xyz-d65 0.2 0.5 1 / 0.8)");
+    }
+
+    #[test]
+    fn round_trips() {
+        let colors = [
+            "rgb(12, 34, 56)",
+            "rgba(255, 0, 128, 0.4)",
+            "lab(54.29 80.8 69.89)",
+            "lch(54.29 106.84 40.85 / 0.5)",
+            "oklab(0.62796 0.22486 0.12585)",
+            "oklch(0.62796 0.25768 29.23388 / none)",
+            "color(srgb 0.25 none 1)",
+            "color(srgb-linear 0.5 none 0)",
+            "color(display-p3 1 0.5 0)",
+            "color(a98-rgb 0.1 0.2 0.3)",
+            "color(prophoto-rgb 0.1 0.2 0.3)",
+            "color(xyz-d50 0.1 0.2 0.3)",
+        ];
+
+        for &color in &colors {
+            assert_eq!(parse(color).unwrap().to_string(), color);
+        }
+    }
+
+    #[test]
+    fn converts() {
+        let white = parse("lab(100 0 0)").unwrap().to_srgb();
+        assert!((white.color.r - 1.0).abs() < 1e-3);
+        assert!((white.color.g - 1.0).abs() < 1e-3);
+        assert!((white.color.b - 1.0).abs() < 1e-3);
+
+        let red = parse("hsl(0 100% 50% / 0.5)").unwrap().to_srgb();
+        assert_eq!(
+            red,
+            Alpha::new(
+                Srgb {
+                    r: 1.0,
+                    g: 0.0,
+                    b: 0.0
+                },
+                0.5
+            )
+        );
+    }
+
+    #[test]
+    fn to_space_carries_missing_components() {
+        let lch = parse("lch(none 40 120 / none)").unwrap();
+        let oklab = lch.to_space(Space::Oklab);
+        assert_eq!(oklab.space, Space::Oklab);
+        assert_eq!(oklab.components[0], None);
+        assert!(oklab.components[1].is_some() && oklab.components[2].is_some());
+        assert_eq!(oklab.alpha, None);
+
+        let rgb = parse("rgb(none 255 0)").unwrap();
+        assert_eq!(rgb.to_space(Space::DisplayP3).components[0], None);
+        assert!(rgb
+            .to_space(Space::Hsl)
+            .components
+            .iter()
+            .all(Option::is_some));
+    }
+}
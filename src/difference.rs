@@ -0,0 +1,505 @@
+//! Measures of how different two colors look.
+//!
+//! Each function accepts colors from any [`CoreColorSpace`] and converts them to the color space the measure is defined in.
+//! The CIE measures use [`Lab`] relative to the white point of the colors’ own color space.
+//! For the CIE measures and [`itp`] a difference of about 1 is just noticeable; for [`ok`] it is about 0.02.
+//!
+//! ```
+//! use tincture::illuminant::D65;
+//! use tincture::{difference, Lab};
+//!
+//! let a: Lab<D65, f64> = Lab::new(50.0, 2.6772, -79.7751);
+//! let b: Lab<D65, f64> = Lab::new(50.0, 0.0, -82.7485);
+//!
+//! assert!((difference::ciede2000(a, b) - 2.0425).abs() < 0.0001);
+//! ```
+
+use crate::{CoreColorSpace, Float, Lab, Oklab};
+
+/// The application that [`cie94`] is weighted for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Cie94Application {
+    /// Graphic arts, where lightness differences are as important as the others.
+    GraphicArts,
+    /// Textiles, where lightness differences are half as important.
+    Textiles,
+}
+
+impl Cie94Application {
+    fn constants(self) -> (f64, f64, f64) {
+        match self {
+            Self::GraphicArts => (1.0, 0.045, 0.015),
+            Self::Textiles => (2.0, 0.048, 0.014),
+        }
+    }
+}
+
+fn lab<C: CoreColorSpace>(color: C) -> Lab<C::WhitePoint, C::Component> {
+    crate::convert(color)
+}
+
+fn chroma<T: Float>(a: T, b: T) -> T {
+    (a * a + b * b).sqrt()
+}
+
+/// The CIE 1976 color difference (ΔE*ab): the Euclidean distance in [`Lab`].
+pub fn cie76<C: CoreColorSpace>(a: C, b: C) -> C::Component {
+    let (a, b) = (lab(a), lab(b));
+
+    ((a.l - b.l).powi(2) + (a.a - b.a).powi(2) + (a.b - b.b).powi(2)).sqrt()
+}
+
+/// The CIE 1994 color difference (ΔE*94) of `sample` from `reference`.
+///
+/// This measure is not symmetric: the chroma of `reference` weights the result.
+pub fn cie94<C: CoreColorSpace>(
+    reference: C,
+    sample: C,
+    application: Cie94Application,
+) -> C::Component {
+    let (k_l, k_1, k_2) = application.constants();
+    let (k_l, k_1, k_2) = (
+        C::Component::from_f64(k_l),
+        C::Component::from_f64(k_1),
+        C::Component::from_f64(k_2),
+    );
+
+    let (reference, sample) = (lab(reference), lab(sample));
+
+    let c_1 = chroma(reference.a, reference.b);
+    let c_2 = chroma(sample.a, sample.b);
+
+    let delta_l = reference.l - sample.l;
+    let delta_c = c_1 - c_2;
+    let delta_h_squared = ((reference.a - sample.a).powi(2) + (reference.b - sample.b).powi(2)
+        - delta_c.powi(2))
+    .max(C::Component::ZERO);
+
+    let s_c = C::Component::ONE + k_1 * c_1;
+    let s_h = C::Component::ONE + k_2 * c_1;
+
+    ((delta_l / k_l).powi(2) + (delta_c / s_c).powi(2) + delta_h_squared / s_h.powi(2)).sqrt()
+}
+
+/// The CIEDE2000 color difference (ΔE00).
+pub fn ciede2000<C: CoreColorSpace>(a: C, b: C) -> C::Component {
+    let f = C::Component::from_f64;
+    let (lab_1, lab_2) = (lab(a), lab(b));
+
+    let c_bar = (chroma(lab_1.a, lab_1.b) + chroma(lab_2.a, lab_2.b)) / f(2.0);
+    let c_bar_7 = c_bar.powi(7);
+    let g = f(0.5) * (C::Component::ONE - (c_bar_7 / (c_bar_7 + f(25.0_f64.powi(7)))).sqrt());
+
+    let a_1 = (C::Component::ONE + g) * lab_1.a;
+    let a_2 = (C::Component::ONE + g) * lab_2.a;
+    let c_1 = chroma(a_1, lab_1.b);
+    let c_2 = chroma(a_2, lab_2.b);
+
+    let hue = |a: C::Component, b: C::Component| {
+        if a == C::Component::ZERO && b == C::Component::ZERO {
+            C::Component::ZERO
+        } else {
+            b.atan2(a).to_degrees().rem_euclid(f(360.0))
+        }
+    };
+    let h_1 = hue(a_1, lab_1.b);
+    let h_2 = hue(a_2, lab_2.b);
+
+    let achromatic = c_1 * c_2 == C::Component::ZERO;
+
+    let delta_l = lab_2.l - lab_1.l;
+    let delta_c = c_2 - c_1;
+    let delta_h = if achromatic {
+        C::Component::ZERO
+    } else if h_2 - h_1 > f(180.0) {
+        h_2 - h_1 - f(360.0)
+    } else if h_2 - h_1 < f(-180.0) {
+        h_2 - h_1 + f(360.0)
+    } else {
+        h_2 - h_1
+    };
+    let delta_h = f(2.0) * (c_1 * c_2).sqrt() * (delta_h / f(2.0)).to_radians().sin();
+
+    let l_bar = (lab_1.l + lab_2.l) / f(2.0);
+    let c_bar = (c_1 + c_2) / f(2.0);
+    let h_bar = if achromatic {
+        h_1 + h_2
+    } else if (h_1 - h_2).abs() <= f(180.0) {
+        (h_1 + h_2) / f(2.0)
+    } else if h_1 + h_2 < f(360.0) {
+        (h_1 + h_2 + f(360.0)) / f(2.0)
+    } else {
+        (h_1 + h_2 - f(360.0)) / f(2.0)
+    };
+
+    let cos = |degrees: C::Component| degrees.to_radians().cos();
+    let t = C::Component::ONE - f(0.17) * cos(h_bar - f(30.0))
+        + f(0.24) * cos(f(2.0) * h_bar)
+        + f(0.32) * cos(f(3.0) * h_bar + f(6.0))
+        - f(0.20) * cos(f(4.0) * h_bar - f(63.0));
+
+    let delta_theta = f(30.0) * (-((h_bar - f(275.0)) / f(25.0)).powi(2)).exp();
+    let c_bar_7 = c_bar.powi(7);
+    let r_c = f(2.0) * (c_bar_7 / (c_bar_7 + f(25.0_f64.powi(7)))).sqrt();
+    let r_t = -(f(2.0) * delta_theta).to_radians().sin() * r_c;
+
+    let s_l = C::Component::ONE
+        + f(0.015) * (l_bar - f(50.0)).powi(2) / (f(20.0) + (l_bar - f(50.0)).powi(2)).sqrt();
+    let s_c = C::Component::ONE + f(0.045) * c_bar;
+    let s_h = C::Component::ONE + f(0.015) * c_bar * t;
+
+    let l = delta_l / s_l;
+    let c = delta_c / s_c;
+    let h = delta_h / s_h;
+
+    (l * l + c * c + h * h + r_t * c * h).sqrt()
+}
+
+/// The CMC l:c color difference (ΔE CMC) of `sample` from `reference`,
+/// with the weights `l` for lightness and `c` for chroma.
+///
+/// The weights are usually 2:1 for judging acceptability and 1:1 for judging perceptibility.
+/// This measure is not symmetric: the lightness, chroma and hue of `reference` weight the result.
+pub fn cmc<C: CoreColorSpace>(
+    reference: C,
+    sample: C,
+    l: C::Component,
+    c: C::Component,
+) -> C::Component {
+    let f = C::Component::from_f64;
+    let (reference, sample) = (lab(reference), lab(sample));
+
+    let c_1 = chroma(reference.a, reference.b);
+    let c_2 = chroma(sample.a, sample.b);
+
+    let delta_l = reference.l - sample.l;
+    let delta_c = c_1 - c_2;
+    let delta_h_squared = ((reference.a - sample.a).powi(2) + (reference.b - sample.b).powi(2)
+        - delta_c.powi(2))
+    .max(C::Component::ZERO);
+
+    let h_1 = reference
+        .b
+        .atan2(reference.a)
+        .to_degrees()
+        .rem_euclid(f(360.0));
+
+    let t = if (f(164.0)..=f(345.0)).contains(&h_1) {
+        f(0.56) + (f(0.2) * (h_1 + f(168.0)).to_radians().cos()).abs()
+    } else {
+        f(0.36) + (f(0.4) * (h_1 + f(35.0)).to_radians().cos()).abs()
+    };
+
+    let c_1_4 = c_1.powi(4);
+    let f_ = (c_1_4 / (c_1_4 + f(1900.0))).sqrt();
+
+    let s_l = if reference.l < f(16.0) {
+        f(0.511)
+    } else {
+        f(0.040975) * reference.l / (C::Component::ONE + f(0.01765) * reference.l)
+    };
+    let s_c = f(0.0638) * c_1 / (C::Component::ONE + f(0.0131) * c_1) + f(0.638);
+    let s_h = s_c * (f_ * t + C::Component::ONE - f_);
+
+    ((delta_l / (l * s_l)).powi(2) + (delta_c / (c * s_c)).powi(2) + delta_h_squared / s_h.powi(2))
+        .sqrt()
+}
+
+/// The Euclidean distance in [`Oklab`] (ΔEOK), as used by CSS.
+pub fn ok<C: CoreColorSpace>(a: C, b: C) -> C::Component {
+    let a: Oklab<C::Component> = crate::convert(a);
+    let b: Oklab<C::Component> = crate::convert(b);
+
+    ((a.l - b.l).powi(2) + (a.a - b.a).powi(2) + (a.b - b.b).powi(2)).sqrt()
+}
+
+/// The luminance in cd/m² of a color with an XYZ Y of 1, the reference white of SDR content in HDR.
+const REFERENCE_WHITE: f64 = 203.0;
+
+/// The matrix converting linear Rec. 2020 to the LMS cone responses of ICtCp.
+const REC2020_TO_LMS: crate::matrix::Matrix = [
+    [1688.0 / 4096.0, 2146.0 / 4096.0, 262.0 / 4096.0],
+    [683.0 / 4096.0, 2951.0 / 4096.0, 462.0 / 4096.0],
+    [99.0 / 4096.0, 309.0 / 4096.0, 3688.0 / 4096.0],
+];
+
+const XYZ_TO_LMS: crate::matrix::Matrix = crate::matrix::mul(
+    REC2020_TO_LMS,
+    <crate::LinearRec2020 as CoreColorSpace>::XYZ_TO_LINEAR,
+);
+
+const LMS_TO_ICTCP: crate::matrix::Matrix = [
+    [2048.0 / 4096.0, 2048.0 / 4096.0, 0.0],
+    [6610.0 / 4096.0, -13613.0 / 4096.0, 7003.0 / 4096.0],
+    [17933.0 / 4096.0, -17390.0 / 4096.0, -543.0 / 4096.0],
+];
+
+/// The ΔE ITP color difference from ITU-R BT.2124, designed for HDR and wide color gamut content.
+///
+/// An XYZ Y of 1 is taken to be 203 cd/m², the reference white of SDR content in HDR.
+pub fn itp<C: CoreColorSpace>(a: C, b: C) -> C::Component {
+    let a = ictcp(a);
+    let b = ictcp(b);
+
+    let delta_i = a[0] - b[0];
+    let delta_t = (a[1] - b[1]) * C::Component::from_f64(0.5);
+    let delta_p = a[2] - b[2];
+
+    C::Component::from_f64(720.0)
+        * (delta_i * delta_i + delta_t * delta_t + delta_p * delta_p).sqrt()
+}
+
+fn ictcp<C: CoreColorSpace>(color: C) -> [C::Component; 3] {
+    let xyz: crate::Xyz<crate::illuminant::D65, C::Component> = crate::convert(color);
+    let lms = crate::matrix::apply(XYZ_TO_LMS, [xyz.x, xyz.y, xyz.z]);
+    let scale = C::Component::from_f64(REFERENCE_WHITE / 10000.0);
+
+    crate::matrix::apply(
+        LMS_TO_ICTCP,
+        lms.map(|n| crate::transfer::pq_encode(n * scale)),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::LinearRgb;
+
+    /// The CIEDE2000 test data from Sharma, Wu and Dalal (2005),
+    /// “The CIEDE2000 Color-Difference Formula: Implementation Notes, Supplementary Test Data, and Mathematical Observations”.
+    const SHARMA: [([f64; 3], [f64; 3], f64); 34] = [
+        (
+            [50.0000, 2.6772, -79.7751],
+            [50.0000, 0.0000, -82.7485],
+            2.0425,
+        ),
+        (
+            [50.0000, 3.1571, -77.2803],
+            [50.0000, 0.0000, -82.7485],
+            2.8615,
+        ),
+        (
+            [50.0000, 2.8361, -74.0200],
+            [50.0000, 0.0000, -82.7485],
+            3.4412,
+        ),
+        (
+            [50.0000, -1.3802, -84.2814],
+            [50.0000, 0.0000, -82.7485],
+            1.0000,
+        ),
+        (
+            [50.0000, -1.1848, -84.8006],
+            [50.0000, 0.0000, -82.7485],
+            1.0000,
+        ),
+        (
+            [50.0000, -0.9009, -85.5211],
+            [50.0000, 0.0000, -82.7485],
+            1.0000,
+        ),
+        (
+            [50.0000, 0.0000, 0.0000],
+            [50.0000, -1.0000, 2.0000],
+            2.3669,
+        ),
+        (
+            [50.0000, -1.0000, 2.0000],
+            [50.0000, 0.0000, 0.0000],
+            2.3669,
+        ),
+        (
+            [50.0000, 2.4900, -0.0010],
+            [50.0000, -2.4900, 0.0009],
+            7.1792,
+        ),
+        (
+            [50.0000, 2.4900, -0.0010],
+            [50.0000, -2.4900, 0.0010],
+            7.1792,
+        ),
+        (
+            [50.0000, 2.4900, -0.0010],
+            [50.0000, -2.4900, 0.0011],
+            7.2195,
+        ),
+        (
+            [50.0000, 2.4900, -0.0010],
+            [50.0000, -2.4900, 0.0012],
+            7.2195,
+        ),
+        (
+            [50.0000, -0.0010, 2.4900],
+            [50.0000, 0.0009, -2.4900],
+            4.8045,
+        ),
+        (
+            [50.0000, -0.0010, 2.4900],
+            [50.0000, 0.0010, -2.4900],
+            4.8045,
+        ),
+        (
+            [50.0000, -0.0010, 2.4900],
+            [50.0000, 0.0011, -2.4900],
+            4.7461,
+        ),
+        (
+            [50.0000, 2.5000, 0.0000],
+            [50.0000, 0.0000, -2.5000],
+            4.3065,
+        ),
+        (
+            [50.0000, 2.5000, 0.0000],
+            [73.0000, 25.0000, -18.0000],
+            27.1492,
+        ),
+        (
+            [50.0000, 2.5000, 0.0000],
+            [61.0000, -5.0000, 29.0000],
+            22.8977,
+        ),
+        (
+            [50.0000, 2.5000, 0.0000],
+            [56.0000, -27.0000, -3.0000],
+            31.9030,
+        ),
+        (
+            [50.0000, 2.5000, 0.0000],
+            [58.0000, 24.0000, 15.0000],
+            19.4535,
+        ),
+        ([50.0000, 2.5000, 0.0000], [50.0000, 3.1736, 0.5854], 1.0000),
+        ([50.0000, 2.5000, 0.0000], [50.0000, 3.2972, 0.0000], 1.0000),
+        ([50.0000, 2.5000, 0.0000], [50.0000, 1.8634, 0.5757], 1.0000),
+        ([50.0000, 2.5000, 0.0000], [50.0000, 3.2592, 0.3350], 1.0000),
+        (
+            [60.2574, -34.0099, 36.2677],
+            [60.4626, -34.1751, 39.4387],
+            1.2644,
+        ),
+        (
+            [63.0109, -31.0961, -5.8663],
+            [62.8187, -29.7946, -4.0864],
+            1.2630,
+        ),
+        (
+            [61.2901, 3.7196, -5.3901],
+            [61.4292, 2.2480, -4.9620],
+            1.8731,
+        ),
+        (
+            [35.0831, -44.1164, 3.7933],
+            [35.0232, -40.0716, 1.5901],
+            1.8645,
+        ),
+        (
+            [22.7233, 20.0904, -46.6940],
+            [23.0331, 14.9730, -42.5619],
+            2.0373,
+        ),
+        (
+            [36.4612, 47.8580, 18.3852],
+            [36.2715, 50.5065, 21.2231],
+            1.4146,
+        ),
+        (
+            [90.8027, -2.0831, 1.4410],
+            [91.1528, -1.6435, 0.0447],
+            1.4441,
+        ),
+        (
+            [90.9257, -0.5406, -0.9208],
+            [88.6381, -0.8985, -0.7239],
+            1.5381,
+        ),
+        (
+            [6.7747, -0.2908, -2.4247],
+            [5.8714, -0.0985, -2.2286],
+            0.6377,
+        ),
+        (
+            [2.0776, 0.0795, -1.1350],
+            [0.9033, -0.0636, -0.5514],
+            0.9082,
+        ),
+    ];
+
+    fn lab([l, a, b]: [f64; 3]) -> Lab<crate::illuminant::D65, f64> {
+        Lab::new(l, a, b)
+    }
+
+    #[test]
+    fn ciede2000_matches_sharma() {
+        for &(a, b, expected) in &SHARMA {
+            let (a, b) = (lab(a), lab(b));
+
+            assert!((ciede2000(a, b) - expected).abs() < 0.0001);
+            assert!((ciede2000(b, a) - expected).abs() < 0.0001);
+        }
+    }
+
+    #[test]
+    fn cie76_is_euclidean() {
+        let a = lab([50.0, 10.0, -10.0]);
+        let b = lab([53.0, 14.0, -10.0]);
+
+        assert!((cie76(a, b) - 5.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn cie94_weights_lightness_for_textiles() {
+        let a = lab([50.0, 0.0, 0.0]);
+        let b = lab([54.0, 0.0, 0.0]);
+
+        assert!((cie94(a, b, Cie94Application::GraphicArts) - 4.0).abs() < 1e-9);
+        assert!((cie94(a, b, Cie94Application::Textiles) - 2.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn cmc_weights_lightness() {
+        let a = lab([50.0, 20.0, 20.0]);
+        let b = lab([52.0, 20.0, 20.0]);
+
+        let acceptability = cmc(a, b, 2.0, 1.0);
+        let perceptibility = cmc(a, b, 1.0, 1.0);
+
+        assert!((perceptibility - 2.0 * acceptability).abs() < 1e-9);
+    }
+
+    #[test]
+    fn identical_colors_have_no_difference() {
+        let color = LinearRgb {
+            r: 0.3_f64,
+            g: 0.6,
+            b: 0.1,
+        };
+
+        assert!(cie76(color, color).abs() < 1e-9);
+        assert!(cie94(color, color, Cie94Application::GraphicArts).abs() < 1e-9);
+        assert!(ciede2000(color, color).abs() < 1e-9);
+        assert!(cmc(color, color, 2.0, 1.0).abs() < 1e-9);
+        assert!(ok(color, color).abs() < 1e-9);
+        assert!(itp(color, color).abs() < 1e-9);
+    }
+
+    #[test]
+    fn itp_grows_with_difference() {
+        let black = LinearRgb::<f64> {
+            r: 0.0,
+            g: 0.0,
+            b: 0.0,
+        };
+        let grey = LinearRgb::<f64> {
+            r: 0.2,
+            g: 0.2,
+            b: 0.2,
+        };
+        let white = LinearRgb::<f64> {
+            r: 1.0,
+            g: 1.0,
+            b: 1.0,
+        };
+
+        assert!(itp(black, grey) < itp(black, white));
+    }
+}
@@ -0,0 +1,45 @@
+/// The [Display P3] color space, for use with [`LinearRgbIn`](crate::LinearRgbIn) and [`EncodedRgbIn`](crate::EncodedRgbIn).
+///
+/// [Display P3]: https://www.color.org/chardata/rgb/DisplayP3.xalter
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct DisplayP3Space;
+
+impl crate::RgbSpaceDefinition for DisplayP3Space {
+    type WhitePoint = crate::illuminant::D65;
+
+    const PRIMARIES: [crate::Chromaticity; 3] = [
+        crate::Chromaticity { x: 0.68, y: 0.32 },
+        crate::Chromaticity { x: 0.265, y: 0.69 },
+        crate::Chromaticity { x: 0.15, y: 0.06 },
+    ];
+
+    const TRANSFER: crate::TransferFunction = crate::TransferFunction::Srgb;
+}
+
+/// A [Display P3] color.
+///
+/// [Display P3]: https://www.color.org/chardata/rgb/DisplayP3.xalter
+pub type DisplayP3<T = f32> = crate::EncodedRgbIn<DisplayP3Space, T>;
+
+/// A [Display P3] color without gamma correction.
+///
+/// [Display P3]: https://www.color.org/chardata/rgb/DisplayP3.xalter
+pub type LinearDisplayP3<T = f32> = crate::LinearRgbIn<DisplayP3Space, T>;
+
+#[cfg(test)]
+#[test]
+fn srgb_red() {
+    use crate::{LinearDisplayP3, LinearRgb, Srgb};
+
+    let red = LinearRgb::from(Srgb {
+        r: 1.0,
+        g: 0.0,
+        b: 0.0,
+    });
+
+    let p3 = DisplayP3::from(crate::convert::<_, LinearDisplayP3>(red));
+
+    assert!((p3.r - 0.9175).abs() < 0.001);
+    assert!((p3.g - 0.2003).abs() < 0.001);
+    assert!((p3.b - 0.1386).abs() < 0.001);
+}
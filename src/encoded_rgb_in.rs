@@ -0,0 +1,101 @@
+use std::fmt;
+use std::marker::PhantomData;
+
+/// A gamma-corrected RGB color in the color space defined by `S`.
+pub struct EncodedRgbIn<S, T = f32> {
+    /// Red (0 to 1).
+    pub r: T,
+    /// Green (0 to 1).
+    pub g: T,
+    /// Blue (0 to 1).
+    pub b: T,
+    space: PhantomData<S>,
+}
+
+impl<S, T> EncodedRgbIn<S, T> {
+    /// Creates a new color from its components.
+    pub const fn new(r: T, g: T, b: T) -> Self {
+        Self {
+            r,
+            g,
+            b,
+            space: PhantomData,
+        }
+    }
+}
+
+impl<S, T: fmt::Debug> fmt::Debug for EncodedRgbIn<S, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EncodedRgbIn")
+            .field("r", &self.r)
+            .field("g", &self.g)
+            .field("b", &self.b)
+            .finish()
+    }
+}
+
+impl<S, T: Copy> Clone for EncodedRgbIn<S, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<S, T: Copy> Copy for EncodedRgbIn<S, T> {}
+
+impl<S, T: PartialEq> PartialEq for EncodedRgbIn<S, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.r == other.r && self.g == other.g && self.b == other.b
+    }
+}
+
+impl<S, T: PartialOrd> PartialOrd for EncodedRgbIn<S, T> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        (&self.r, &self.g, &self.b).partial_cmp(&(&other.r, &other.g, &other.b))
+    }
+}
+
+impl<S: crate::RgbSpaceDefinition, T: crate::Float> From<crate::LinearRgbIn<S, T>>
+    for EncodedRgbIn<S, T>
+{
+    fn from(linear: crate::LinearRgbIn<S, T>) -> Self {
+        let transfer = crate::rgb_space::SpaceOf::<S>::SPACE.transfer();
+
+        Self::new(
+            transfer.encode(linear.r),
+            transfer.encode(linear.g),
+            transfer.encode(linear.b),
+        )
+    }
+}
+
+impl<S, T: crate::Float> crate::ColorSpace for EncodedRgbIn<S, T> {
+    const BLACK: Self = Self::new(T::ZERO, T::ZERO, T::ZERO);
+
+    const WHITE: Self = Self::new(T::ONE, T::ONE, T::ONE);
+
+    fn in_bounds(self) -> bool {
+        crate::approx_in_range(self.r, T::ZERO..T::ONE)
+            && crate::approx_in_range(self.g, T::ZERO..T::ONE)
+            && crate::approx_in_range(self.b, T::ZERO..T::ONE)
+    }
+}
+
+impl<S, T: crate::Float> crate::Hex<T> for EncodedRgbIn<S, T> {
+    fn components(self) -> (T, T, T) {
+        (self.r, self.g, self.b)
+    }
+}
+
+impl<S, T: crate::Float> crate::FromHex<T> for EncodedRgbIn<S, T> {
+    fn from_components((r, g, b): (T, T, T)) -> Self {
+        Self::new(r, g, b)
+    }
+}
+
+impl<S, T: crate::Float> std::str::FromStr for EncodedRgbIn<S, T> {
+    type Err = crate::ParseHexError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        crate::FromHex::from_hex_str(s)
+    }
+}
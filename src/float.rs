@@ -0,0 +1,250 @@
+use std::fmt;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// A floating-point type that can be used for the components of a color.
+///
+/// This is implemented for `f32` and `f64`, and cannot be implemented outside of this crate.
+pub trait Float:
+    sealed::Sealed
+    + fmt::Debug
+    + fmt::Display
+    + Default
+    + Copy
+    + PartialEq
+    + PartialOrd
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+    + AddAssign
+    + SubAssign
+    + MulAssign
+    + DivAssign
+{
+    /// Zero.
+    const ZERO: Self;
+
+    /// One.
+    const ONE: Self;
+
+    /// Converts an `f64` to `Self`, rounding if necessary.
+    fn from_f64(n: f64) -> Self;
+
+    /// Converts `self` to an `f64`.
+    fn to_f64(self) -> f64;
+
+    /// See [`f64::abs`].
+    fn abs(self) -> Self;
+
+    /// See [`f64::sqrt`].
+    fn sqrt(self) -> Self;
+
+    /// See [`f64::cbrt`].
+    fn cbrt(self) -> Self;
+
+    /// See [`f64::powf`].
+    fn powf(self, n: Self) -> Self;
+
+    /// See [`f64::powi`].
+    fn powi(self, n: i32) -> Self;
+
+    /// See [`f64::exp`].
+    fn exp(self) -> Self;
+
+    /// See [`f64::ln`].
+    fn ln(self) -> Self;
+
+    /// See [`f64::sin`].
+    fn sin(self) -> Self;
+
+    /// See [`f64::cos`].
+    fn cos(self) -> Self;
+
+    /// See [`f64::atan2`].
+    fn atan2(self, other: Self) -> Self;
+
+    /// See [`f64::to_radians`].
+    fn to_radians(self) -> Self;
+
+    /// See [`f64::to_degrees`].
+    fn to_degrees(self) -> Self;
+
+    /// See [`f64::rem_euclid`].
+    fn rem_euclid(self, rhs: Self) -> Self;
+
+    /// See [`f64::min`].
+    fn min(self, other: Self) -> Self;
+
+    /// See [`f64::max`].
+    fn max(self, other: Self) -> Self;
+
+    /// See [`f64::clamp`].
+    fn clamp(self, min: Self, max: Self) -> Self;
+
+    /// See [`f64::copysign`].
+    fn copysign(self, sign: Self) -> Self;
+
+    /// See [`f64::round`].
+    fn round(self) -> Self;
+
+    /// See [`f64::is_nan`].
+    fn is_nan(self) -> bool;
+
+    /// See [`f64::total_cmp`].
+    fn total_cmp(&self, other: &Self) -> std::cmp::Ordering;
+
+    /// An approximation of [`f64::cbrt`] that is accurate to within a few units in the last place.
+    ///
+    /// Unlike `cbrt` it only uses arithmetic, so the compiler can vectorize loops that call it.
+    #[doc(hidden)]
+    fn fast_cbrt(self) -> Self;
+}
+
+macro_rules! impl_float {
+    ($t:ident, $cbrt_magic:expr, $iterations:expr) => {
+        impl sealed::Sealed for $t {}
+
+        impl Float for $t {
+            const ZERO: Self = 0.0;
+            const ONE: Self = 1.0;
+
+            fn from_f64(n: f64) -> Self {
+                n as $t
+            }
+
+            fn to_f64(self) -> f64 {
+                f64::from(self)
+            }
+
+            fn abs(self) -> Self {
+                $t::abs(self)
+            }
+
+            fn sqrt(self) -> Self {
+                $t::sqrt(self)
+            }
+
+            fn cbrt(self) -> Self {
+                $t::cbrt(self)
+            }
+
+            fn powf(self, n: Self) -> Self {
+                $t::powf(self, n)
+            }
+
+            fn powi(self, n: i32) -> Self {
+                $t::powi(self, n)
+            }
+
+            fn exp(self) -> Self {
+                $t::exp(self)
+            }
+
+            fn ln(self) -> Self {
+                $t::ln(self)
+            }
+
+            fn sin(self) -> Self {
+                $t::sin(self)
+            }
+
+            fn cos(self) -> Self {
+                $t::cos(self)
+            }
+
+            fn atan2(self, other: Self) -> Self {
+                $t::atan2(self, other)
+            }
+
+            fn to_radians(self) -> Self {
+                $t::to_radians(self)
+            }
+
+            fn to_degrees(self) -> Self {
+                $t::to_degrees(self)
+            }
+
+            fn rem_euclid(self, rhs: Self) -> Self {
+                $t::rem_euclid(self, rhs)
+            }
+
+            fn min(self, other: Self) -> Self {
+                $t::min(self, other)
+            }
+
+            fn max(self, other: Self) -> Self {
+                $t::max(self, other)
+            }
+
+            fn clamp(self, min: Self, max: Self) -> Self {
+                $t::clamp(self, min, max)
+            }
+
+            fn copysign(self, sign: Self) -> Self {
+                $t::copysign(self, sign)
+            }
+
+            fn round(self) -> Self {
+                $t::round(self)
+            }
+
+            fn is_nan(self) -> bool {
+                $t::is_nan(self)
+            }
+
+            fn total_cmp(&self, other: &Self) -> std::cmp::Ordering {
+                $t::total_cmp(self, other)
+            }
+
+            fn fast_cbrt(self) -> Self {
+                let n = self.abs();
+
+                // Dividing the exponent by three gives a guess within a few percent,
+                // which Halley’s method then refines.
+                let mut guess = $t::from_bits(n.to_bits() / 3 + $cbrt_magic);
+                for _ in 0..$iterations {
+                    let cube = guess * guess * guess;
+                    guess *= (cube + 2.0 * n) / (2.0 * cube + n);
+                }
+
+                let root = if n == 0.0 { 0.0 } else { guess };
+                root.copysign(self)
+            }
+        }
+    };
+}
+
+impl_float!(f32, 0x2a51_4067, 2);
+impl_float!(f64, 0x2a9f_7893_782d_a1ce, 3);
+
+/// Converts an `f64` to `T` in a const context, where [`Float::from_f64`] cannot be called.
+pub(crate) const fn const_from_f64<T: Float>(n: f64) -> T {
+    union Bits<T: Copy> {
+        single: f32,
+        double: f64,
+        float: T,
+    }
+
+    // SAFETY: `Float` is sealed and only implemented for `f32` and `f64`,
+    // so `T` is whichever of the two has its size, and the bytes read are the ones written.
+    if std::mem::size_of::<T>() == std::mem::size_of::<f32>() {
+        unsafe { Bits { single: n as f32 }.float }
+    } else {
+        unsafe { Bits { double: n }.float }
+    }
+}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+#[cfg(test)]
+#[test]
+fn converts_in_const_context() {
+    const SINGLE: f32 = const_from_f64(0.96422);
+    const DOUBLE: f64 = const_from_f64(0.96422);
+
+    assert_eq!(SINGLE, 0.96422_f32);
+    assert_eq!(DOUBLE, 0.96422_f64);
+}
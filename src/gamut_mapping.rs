@@ -0,0 +1,284 @@
+use crate::{Converter, CoreColorSpace, Float, Oklab};
+
+/// A core color space whose linear stage holds RGB components with a gamut of 0 to 1,
+/// such as [`LinearRgb`](crate::LinearRgb) and [`LinearDisplayP3`](crate::LinearDisplayP3).
+///
+/// These are the color spaces that [`gamut_map`] can map into.
+/// The gamut of the corresponding encoded color space (such as [`Srgb`](crate::Srgb)) is the same,
+/// so map into the linear color space and convert the result with `From`.
+pub trait RgbGamut: CoreColorSpace + Copy {}
+
+impl<T: Float> RgbGamut for crate::LinearRgb<T> {}
+impl<S: crate::RgbSpaceDefinition, T: Float> RgbGamut for crate::LinearRgbIn<S, T> {}
+
+/// A strategy for bringing colors that are out of gamut into gamut.
+///
+/// All strategies keep colors that are already in gamut as they are,
+/// and map colors at least as light as white to white and at least as dark as black to black.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum GamutMapping {
+    /// The [CSS Color Level 4 algorithm][css]:
+    /// reduce Oklch chroma with a binary search,
+    /// stopping as soon as clipping the color changes it by less than a just-noticeable difference (ΔEOK of 0.02).
+    ///
+    /// This keeps lightness and hue while staying as vivid as possible.
+    ///
+    /// [css]: https://www.w3.org/TR/css-color-4/#gamut-mapping
+    #[default]
+    Css,
+    /// Clamp each RGB component to 0 to 1.
+    ///
+    /// This is the fastest strategy, but it can shift hue and lightness noticeably.
+    Clip,
+    /// Reduce Oklch chroma until the color is in gamut,
+    /// preserving lightness and hue exactly.
+    ReduceChroma,
+    /// Move the color in a straight line through Oklab toward mid-grey until it is in gamut,
+    /// preserving hue but changing both lightness and chroma.
+    MidGrey,
+}
+
+const JND: f64 = 0.02;
+const EPSILON: f64 = 0.0001;
+
+/// Convert a color to the RGB color space `Out`, bringing it into gamut using `mapping`.
+///
+/// ```
+/// use tincture::{ColorSpace, GamutMapping, Hue, LinearRgb, Oklab, Oklch, Srgb};
+///
+/// let vivid = Oklch {
+///     l: 0.7,
+///     c: 0.4,
+///     h: Hue::from_degrees(150.0).unwrap(),
+/// };
+///
+/// let unmapped: LinearRgb = tincture::convert(Oklab::from(vivid));
+/// assert!(!unmapped.in_bounds());
+///
+/// let linear_rgb: LinearRgb = tincture::gamut_map(Oklab::from(vivid), GamutMapping::Css);
+/// assert!(Srgb::from(linear_rgb).in_bounds());
+/// ```
+pub fn gamut_map<In, Out>(color: In, mapping: GamutMapping) -> Out
+where
+    In: CoreColorSpace,
+    Out: RgbGamut<Component = In::Component>,
+{
+    let oklab: Oklab<In::Component> = crate::convert(color);
+    Mapper::new().map(oklab, mapping)
+}
+
+struct Mapper<Out: CoreColorSpace> {
+    to_rgb: Converter<Oklab<Out::Component>, Out>,
+    to_oklab: Converter<Out, Oklab<Out::Component>>,
+}
+
+impl<T: Float, Out: RgbGamut<Component = T>> Mapper<Out> {
+    fn new() -> Self {
+        Self {
+            to_rgb: Converter::new(),
+            to_oklab: Converter::new(),
+        }
+    }
+
+    fn map(&self, oklab: Oklab<T>, mapping: GamutMapping) -> Out {
+        if oklab.l >= T::ONE {
+            return Out::from_linear([T::ONE; 3]);
+        }
+        if oklab.l <= T::ZERO {
+            return Out::from_linear([T::ZERO; 3]);
+        }
+
+        let rgb = self.to_rgb.convert(oklab);
+        if in_gamut(rgb) {
+            return rgb;
+        }
+
+        match mapping {
+            GamutMapping::Css => self.css(oklab),
+            GamutMapping::Clip => clip(rgb),
+            GamutMapping::ReduceChroma => self.search(
+                oklab,
+                Oklab {
+                    a: T::ZERO,
+                    b: T::ZERO,
+                    ..oklab
+                },
+            ),
+            GamutMapping::MidGrey => self.search(
+                oklab,
+                Oklab {
+                    l: T::from_f64(0.5),
+                    a: T::ZERO,
+                    b: T::ZERO,
+                },
+            ),
+        }
+    }
+
+    fn css(&self, oklab: Oklab<T>) -> Out {
+        let jnd = T::from_f64(JND);
+        let epsilon = T::from_f64(EPSILON);
+
+        let mut clipped = clip(self.to_rgb.convert(oklab));
+        if self.delta_e(oklab, clipped) < jnd {
+            return clipped;
+        }
+
+        let chroma = (oklab.a.powi(2) + oklab.b.powi(2)).sqrt();
+        let mut min = T::ZERO;
+        let mut max = chroma;
+        let mut min_in_gamut = true;
+
+        while max - min > epsilon {
+            let mid = (min + max) / T::from_f64(2.0);
+            let current = with_chroma(oklab, chroma, mid);
+            let rgb = self.to_rgb.convert(current);
+
+            if min_in_gamut && in_gamut(rgb) {
+                min = mid;
+                continue;
+            }
+
+            clipped = clip(rgb);
+            let e = self.delta_e(current, clipped);
+
+            if e < jnd {
+                if jnd - e < epsilon {
+                    return clipped;
+                }
+
+                min_in_gamut = false;
+                min = mid;
+            } else {
+                max = mid;
+            }
+        }
+
+        clipped
+    }
+
+    /// Finds the color closest to `oklab` on the line to `target` that is in gamut,
+    /// assuming that `target` is in gamut.
+    fn search(&self, oklab: Oklab<T>, target: Oklab<T>) -> Out {
+        let lerp = |t: T| Oklab {
+            l: oklab.l + (target.l - oklab.l) * t,
+            a: oklab.a + (target.a - oklab.a) * t,
+            b: oklab.b + (target.b - oklab.b) * t,
+        };
+
+        let mut min = T::ZERO;
+        let mut max = T::ONE;
+
+        while max - min > T::from_f64(EPSILON) {
+            let mid = (min + max) / T::from_f64(2.0);
+
+            if in_gamut(self.to_rgb.convert(lerp(mid))) {
+                max = mid;
+            } else {
+                min = mid;
+            }
+        }
+
+        // The search leaves the color just inside the gamut up to rounding, which clipping removes.
+        clip(self.to_rgb.convert(lerp(max)))
+    }
+
+    fn delta_e(&self, oklab: Oklab<T>, rgb: Out) -> T {
+        let other = self.to_oklab.convert(rgb);
+
+        ((oklab.l - other.l).powi(2) + (oklab.a - other.a).powi(2) + (oklab.b - other.b).powi(2))
+            .sqrt()
+    }
+}
+
+fn with_chroma<T: Float>(oklab: Oklab<T>, chroma: T, new_chroma: T) -> Oklab<T> {
+    let scale = new_chroma / chroma;
+
+    Oklab {
+        l: oklab.l,
+        a: oklab.a * scale,
+        b: oklab.b * scale,
+    }
+}
+
+fn in_gamut<Out: RgbGamut>(rgb: Out) -> bool {
+    let range = Out::Component::ZERO..=Out::Component::ONE;
+    rgb.to_linear().iter().all(|n| range.contains(n))
+}
+
+fn clip<Out: RgbGamut>(rgb: Out) -> Out {
+    Out::from_linear(
+        rgb.to_linear()
+            .map(|n| n.clamp(Out::Component::ZERO, Out::Component::ONE)),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Hue, LinearDisplayP3, LinearRgb, Oklch};
+
+    const STRATEGIES: [GamutMapping; 4] = [
+        GamutMapping::Css,
+        GamutMapping::Clip,
+        GamutMapping::ReduceChroma,
+        GamutMapping::MidGrey,
+    ];
+
+    fn vivid(degrees: f64) -> Oklab<f64> {
+        Oklab::from(Oklch {
+            l: 0.6,
+            c: 0.4,
+            h: Hue::from_degrees(degrees).unwrap(),
+        })
+    }
+
+    #[test]
+    fn maps_into_gamut() {
+        for degrees in (0..360).step_by(15) {
+            for &mapping in &STRATEGIES {
+                let rgb: LinearRgb<f64> = gamut_map(vivid(f64::from(degrees)), mapping);
+                assert!(in_gamut(rgb), "{:?} at {}°", mapping, degrees);
+            }
+        }
+    }
+
+    #[test]
+    fn keeps_in_gamut_colors() {
+        let rgb = LinearRgb {
+            r: 0.2_f64,
+            g: 0.5,
+            b: 0.7,
+        };
+
+        for &mapping in &STRATEGIES {
+            let mapped: LinearRgb<f64> = gamut_map(rgb, mapping);
+            assert!((mapped.r - rgb.r).abs() < 1e-12);
+            assert!((mapped.g - rgb.g).abs() < 1e-12);
+            assert!((mapped.b - rgb.b).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn reduce_chroma_preserves_lightness_and_hue() {
+        let oklab = vivid(200.0);
+        let rgb: LinearDisplayP3<f64> = gamut_map(oklab, GamutMapping::ReduceChroma);
+        let mapped = Oklch::from(crate::convert::<_, Oklab<f64>>(rgb));
+
+        assert!((mapped.l - oklab.l).abs() < 1e-3);
+        assert!((mapped.h.to_degrees() - 200.0).abs() < 0.5);
+        assert!(mapped.c < 0.4);
+    }
+
+    #[test]
+    fn css_stays_close_to_reduced_chroma() {
+        let oklab = vivid(30.0);
+        let css: LinearRgb<f64> = gamut_map(oklab, GamutMapping::Css);
+        let reduced: LinearRgb<f64> = gamut_map(oklab, GamutMapping::ReduceChroma);
+
+        let css: Oklab<f64> = crate::convert(css);
+        let reduced: Oklab<f64> = crate::convert(reduced);
+
+        assert!((css.l - reduced.l).abs() < 0.02);
+    }
+}
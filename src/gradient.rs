@@ -0,0 +1,349 @@
+//! Gradients through any number of colors, like CSS gradients.
+//!
+//! A [`Gradient`] interpolates between its color stops in the color space of the stops,
+//! so the same colors give different gradients in different color spaces.
+//! Gradients in [`Oklab`] avoid the dull and dark middles of gradients in [`Srgb`]:
+//!
+//! ```
+//! use tincture::gradient::Gradient;
+//! use tincture::{LinearRgb, Oklab, Srgb};
+//!
+//! let oklab = |srgb: Srgb| -> Oklab { tincture::convert(LinearRgb::from(srgb)) };
+//! let blue = oklab(Srgb { r: 0.0, g: 0.0, b: 1.0 });
+//! let yellow = oklab(Srgb { r: 1.0, g: 1.0, b: 0.0 });
+//!
+//! let gradient = Gradient::new(blue, yellow);
+//! let lut = gradient.hex_lut(256);
+//!
+//! assert_eq!(lut[0], 0x0000ff);
+//! assert_eq!(lut[255], 0xffff00);
+//! ```
+
+use crate::{Float, Hex, HueInterpolation, Interpolate, LinearRgb, Srgb};
+
+/// A color space that gradients can interpolate in.
+pub trait Space<T: Float>: Interpolate<T> + Copy {
+    /// Converts the color to [`Srgb`] for display.
+    fn to_srgb(self) -> Srgb<T>;
+}
+
+fn srgb_to_srgb<C, T>(color: C) -> Srgb<T>
+where
+    Srgb<T>: From<C>,
+{
+    Srgb::from(color)
+}
+
+fn core_to_srgb<C, T>(color: C) -> Srgb<T>
+where
+    C: crate::CoreColorSpace<Component = T>,
+    T: Float,
+{
+    Srgb::from(crate::convert::<C, LinearRgb<T>>(color))
+}
+
+macro_rules! impl_space {
+    ($ty:ty, [$($param:ident: $bound:path),*], $to_srgb:ident) => {
+        impl<$($param: $bound,)* T: Float> Space<T> for $ty {
+            fn to_srgb(self) -> Srgb<T> {
+                $to_srgb(self)
+            }
+        }
+    };
+    ($ty:ty, [$($param:ident: $bound:path),*], $to_srgb:ident via $core:path) => {
+        impl<$($param: $bound,)* T: Float> Space<T> for $ty {
+            fn to_srgb(self) -> Srgb<T> {
+                $to_srgb(<$core>::from(self))
+            }
+        }
+    };
+}
+
+impl_space!(Srgb<T>, [], srgb_to_srgb);
+impl_space!(LinearRgb<T>, [], srgb_to_srgb);
+impl_space!(crate::Hsl<T>, [], srgb_to_srgb);
+impl_space!(crate::Hsv<T>, [], srgb_to_srgb);
+impl_space!(crate::Hwb<T>, [], srgb_to_srgb);
+impl_space!(crate::Oklab<T>, [], core_to_srgb);
+impl_space!(crate::Oklch<T>, [], core_to_srgb via crate::Oklab<T>);
+impl_space!(crate::Okhsv<T>, [], core_to_srgb via crate::Oklab<T>);
+impl_space!(crate::Okhsl<T>, [], core_to_srgb via crate::Oklab<T>);
+impl_space!(crate::Xyz<W, T>, [W: crate::WhitePoint], core_to_srgb);
+impl_space!(crate::Lab<W, T>, [W: crate::WhitePoint], core_to_srgb);
+impl_space!(crate::Lch<W, T>, [W: crate::WhitePoint], core_to_srgb via crate::Lab<W, T>);
+impl_space!(crate::Luv<W, T>, [W: crate::WhitePoint], core_to_srgb);
+impl_space!(crate::Lchuv<W, T>, [W: crate::WhitePoint], core_to_srgb via crate::Luv<W, T>);
+impl_space!(crate::LinearRgbIn<S, T>, [S: crate::RgbSpaceDefinition], core_to_srgb);
+impl_space!(crate::EncodedRgbIn<S, T>, [S: crate::RgbSpaceDefinition], core_to_srgb via crate::LinearRgbIn<S, T>);
+
+/// A function shaping the progress between two color stops, as in CSS.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Easing {
+    /// Constant speed.
+    #[default]
+    Linear,
+    /// CSS’s `ease`: speeds up quickly and slows down gradually.
+    Ease,
+    /// CSS’s `ease-in`: starts slowly.
+    EaseIn,
+    /// CSS’s `ease-out`: ends slowly.
+    EaseOut,
+    /// CSS’s `ease-in-out`: starts and ends slowly.
+    EaseInOut,
+    /// CSS’s `cubic-bezier(x1, y1, x2, y2)`: a cubic Bézier curve from (0, 0) to (1, 1)
+    /// with the control points (x1, y1) and (x2, y2).
+    ///
+    /// `x1` and `x2` must be between 0 and 1.
+    CubicBezier(f64, f64, f64, f64),
+}
+
+impl Easing {
+    /// Applies the easing function to `progress`, which is from 0 to 1.
+    ///
+    /// ```
+    /// use tincture::gradient::Easing;
+    ///
+    /// assert_eq!(Easing::Linear.apply(0.3), 0.3);
+    /// assert!(Easing::EaseIn.apply(0.3) < 0.3);
+    /// assert!((Easing::EaseInOut.apply(0.5) - 0.5).abs() < 1e-6);
+    /// ```
+    pub fn apply(self, progress: f64) -> f64 {
+        let (x1, y1, x2, y2) = match self {
+            Self::Linear => return progress,
+            Self::Ease => (0.25, 0.1, 0.25, 1.0),
+            Self::EaseIn => (0.42, 0.0, 1.0, 1.0),
+            Self::EaseOut => (0.0, 0.0, 0.58, 1.0),
+            Self::EaseInOut => (0.42, 0.0, 0.58, 1.0),
+            Self::CubicBezier(x1, y1, x2, y2) => (x1, y1, x2, y2),
+        };
+
+        if progress <= 0.0 || progress >= 1.0 {
+            return progress;
+        }
+
+        let bezier = |p1: f64, p2: f64, s: f64| {
+            let inverse = 1.0 - s;
+            3.0 * inverse * inverse * s * p1 + 3.0 * inverse * s * s * p2 + s * s * s
+        };
+
+        // Find the parameter of the curve at which its x is `progress` by bisection,
+        // which always converges since x increases along the curve.
+        let mut low = 0.0;
+        let mut high = 1.0;
+
+        while high - low > BEZIER_EPSILON {
+            let mid = (low + high) / 2.0;
+
+            if bezier(x1, x2, mid) < progress {
+                low = mid;
+            } else {
+                high = mid;
+            }
+        }
+
+        bezier(y1, y2, (low + high) / 2.0)
+    }
+}
+
+const BEZIER_EPSILON: f64 = 1e-9;
+
+/// A gradient through color stops in the color space `C`.
+///
+/// Stops are kept sorted by position.
+/// As in CSS, sampling before the first stop or after the last gives the color of that stop,
+/// and two stops at the same position make a hard edge.
+///
+/// ```
+/// use tincture::gradient::{Easing, Gradient};
+/// use tincture::{Hue, HueInterpolation, Oklch};
+///
+/// let hue = |degrees| Hue::from_degrees(degrees).unwrap();
+/// let red: Oklch = Oklch { l: 0.6, c: 0.2, h: hue(30.0) };
+/// let green: Oklch = Oklch { l: 0.8, c: 0.2, h: hue(140.0) };
+/// let blue: Oklch = Oklch { l: 0.5, c: 0.2, h: hue(260.0) };
+///
+/// let gradient = Gradient::new(red, blue)
+///     .stop(0.5, green)
+///     .hint(0.25)
+///     .easing(Easing::EaseInOut)
+///     .hue_interpolation(HueInterpolation::Increasing);
+///
+/// let colors = gradient.colors(5);
+/// assert_eq!(colors.len(), 5);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Gradient<C, T = f32> {
+    stops: Vec<(T, C)>,
+    hints: Vec<T>,
+    easing: Easing,
+    hue_interpolation: HueInterpolation,
+}
+
+impl<C: Space<T>, T: Float> Gradient<C, T> {
+    /// Creates a gradient from `start` at position 0 to `end` at position 1.
+    pub fn new(start: C, end: C) -> Self {
+        Self {
+            stops: vec![(T::ZERO, start), (T::ONE, end)],
+            hints: Vec::new(),
+            easing: Easing::Linear,
+            hue_interpolation: HueInterpolation::Shorter,
+        }
+    }
+
+    /// Adds a color stop at `position`.
+    ///
+    /// A stop at the same position as existing stops is placed after them.
+    pub fn stop(mut self, position: T, color: C) -> Self {
+        let index = self
+            .stops
+            .iter()
+            .position(|&(stop, _)| stop > position)
+            .unwrap_or(self.stops.len());
+
+        self.stops.insert(index, (position, color));
+        self
+    }
+
+    /// Adds a color hint at `position`:
+    /// the position at which the gradient is halfway between the stops on either side.
+    ///
+    /// Hints at or outside the stops on either side are ignored.
+    pub fn hint(mut self, position: T) -> Self {
+        self.hints.push(position);
+        self
+    }
+
+    /// Sets the easing function applied between each pair of stops.
+    pub fn easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Sets how hues are interpolated if `C` has a hue.
+    pub fn hue_interpolation(mut self, hue_interpolation: HueInterpolation) -> Self {
+        self.hue_interpolation = hue_interpolation;
+        self
+    }
+
+    /// The color of the gradient at `position`.
+    pub fn sample(&self, position: T) -> C {
+        // The last stop at or before `position` starts the segment containing it.
+        let (before, after) = match self.stops.iter().rposition(|&(stop, _)| stop <= position) {
+            None => return self.stops[0].1,
+            Some(index) if index == self.stops.len() - 1 => return self.stops[index].1,
+            Some(index) => (self.stops[index], self.stops[index + 1]),
+        };
+
+        let length = after.0 - before.0;
+        let mut progress = (position - before.0) / length;
+
+        if let Some(&hint) = self
+            .hints
+            .iter()
+            .rev()
+            .find(|&&hint| before.0 < hint && hint < after.0)
+        {
+            // Bend the progress so that it reaches one half at the hint.
+            let hint = (hint - before.0) / length;
+            progress = progress.powf(T::from_f64(0.5).ln() / hint.ln());
+        }
+
+        let t = T::from_f64(self.easing.apply(progress.to_f64()));
+        before
+            .1
+            .interpolate_with(after.1, t, self.hue_interpolation)
+    }
+
+    /// `n` colors evenly spaced from the first stop to the last, in sRGB.
+    ///
+    /// Colors may be out of the sRGB gamut if the gradient passes outside of it.
+    pub fn colors(&self, n: usize) -> Vec<Srgb<T>> {
+        let first = self.stops[0].0;
+        let last = self.stops[self.stops.len() - 1].0;
+        let step = if n > 1 {
+            (last - first) / T::from_f64((n - 1) as f64)
+        } else {
+            T::ZERO
+        };
+
+        (0..n)
+            .map(|i| {
+                let position = if i == n - 1 && n > 1 {
+                    last
+                } else {
+                    first + step * T::from_f64(i as f64)
+                };
+
+                self.sample(position).to_srgb()
+            })
+            .collect()
+    }
+
+    /// A lookup table of `n` colors evenly spaced from the first stop to the last, as hex values.
+    ///
+    /// Colors out of the sRGB gamut are clipped.
+    pub fn hex_lut(&self, n: usize) -> Vec<u32> {
+        let clamp = |n: T| n.clamp(T::ZERO, T::ONE);
+
+        self.colors(n)
+            .into_iter()
+            .map(|srgb| {
+                Srgb {
+                    r: clamp(srgb.r),
+                    g: clamp(srgb.g),
+                    b: clamp(srgb.b),
+                }
+                .hex()
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ColorSpace;
+
+    fn grey(n: f64) -> Srgb<f64> {
+        Srgb { r: n, g: n, b: n }
+    }
+
+    #[test]
+    fn stops_and_hard_edges() {
+        let gradient = Gradient::new(grey(0.0), grey(1.0))
+            .stop(0.5, grey(0.2))
+            .stop(0.5, grey(0.8));
+
+        assert_eq!(gradient.sample(-1.0), grey(0.0));
+        assert_eq!(gradient.sample(0.25), grey(0.1));
+        assert_eq!(gradient.sample(0.5), grey(0.8));
+        assert_eq!(gradient.sample(0.75), grey(0.9));
+        assert_eq!(gradient.sample(2.0), grey(1.0));
+    }
+
+    #[test]
+    fn hint_moves_midpoint() {
+        let gradient = Gradient::new(grey(0.0), grey(1.0)).hint(0.2);
+
+        assert!((gradient.sample(0.2).r - 0.5).abs() < 1e-9);
+        assert!(gradient.sample(0.1).r < 0.5);
+        assert!(gradient.sample(0.6).r > 0.5);
+    }
+
+    #[test]
+    fn easing() {
+        let gradient = Gradient::new(grey(0.0), grey(1.0)).easing(Easing::EaseIn);
+
+        assert!(gradient.sample(0.25).r < 0.25);
+        assert!((Easing::CubicBezier(0.0, 0.0, 1.0, 1.0).apply(0.3) - 0.3).abs() < 1e-6);
+    }
+
+    #[test]
+    fn hex_lut() {
+        let gradient = Gradient::new(Srgb::<f64>::BLACK, Srgb::WHITE);
+
+        assert_eq!(gradient.hex_lut(3), vec![0x000000, 0x808080, 0xffffff]);
+        assert_eq!(gradient.hex_lut(1), vec![0x000000]);
+        assert!(gradient.hex_lut(0).is_empty());
+    }
+}
@@ -1,17 +1,195 @@
-/// Allows converting colors into single `u32` hex values.
-pub trait Hex: Sized {
+use std::error::Error;
+use std::fmt;
+
+/// Allows converting colors into single `u32` hex values and hex strings.
+pub trait Hex<T: crate::Float = f32>: Sized {
     /// The components of the color.
     ///
     /// Invariant: each component must have a minimum of 0 and a maximum of 1.
-    fn components(self) -> (f32, f32, f32);
+    fn components(self) -> (T, T, T);
 
     /// Converts the color to a hex value.
     fn hex(self) -> u32 {
         let (c0, c1, c2) = self.components();
 
-        let scale = |n: f32| (n * 255.0).round() as u8;
-        let (c0, c1, c2) = (scale(c0), scale(c1), scale(c2));
+        let (c0, c1, c2) = (to_byte(c0), to_byte(c1), to_byte(c2));
 
         (u32::from(c0) << 16) | (u32::from(c1) << 8) | u32::from(c2)
     }
+
+    /// Formats the color as a CSS hex color, like `#ff8000`.
+    ///
+    /// ```
+    /// use tincture::{FromHex, Hex, Srgb};
+    ///
+    /// let orange: Srgb = Srgb::from_hex(0xff8000);
+    /// assert_eq!(orange.hex_string(), "#ff8000");
+    /// ```
+    fn hex_string(self) -> String {
+        format!("#{:06x}", self.hex())
+    }
+}
+
+/// Allows creating colors from single `u32` hex values and parsing them from hex strings,
+/// the inverse of [`Hex`].
+pub trait FromHex<T: crate::Float = f32>: Sized {
+    /// Creates a color from its components, each from 0 to 1.
+    fn from_components(components: (T, T, T)) -> Self;
+
+    /// Creates a color from a hex value.
+    ///
+    /// ```
+    /// use tincture::{FromHex, Hex, Srgb};
+    ///
+    /// let color: Srgb = Srgb::from_hex(0xff8000);
+    /// assert_eq!(color.hex(), 0xff8000);
+    /// ```
+    fn from_hex(hex: u32) -> Self {
+        Self::from_components((
+            from_byte((hex >> 16) as u8),
+            from_byte((hex >> 8) as u8),
+            from_byte(hex as u8),
+        ))
+    }
+
+    /// Parses a color from a hex string of 3, 4, 6 or 8 digits, optionally preceded by `#`,
+    /// as in CSS.
+    ///
+    /// Strings of 4 or 8 digits include alpha, which is dropped;
+    /// parse into an [`Alpha`](crate::Alpha) to keep it.
+    ///
+    /// ```
+    /// use tincture::{FromHex, ParseHexError, Srgb};
+    ///
+    /// let orange: Srgb = Srgb::from_hex_str("#ff8800").unwrap();
+    /// assert_eq!(Srgb::from_hex_str("f80"), Ok(orange));
+    /// assert_eq!(Srgb::from_hex_str("#ff880080"), Ok(orange));
+    ///
+    /// assert_eq!(
+    ///     Srgb::<f32>::from_hex_str("#ff80zz"),
+    ///     Err(ParseHexError::InvalidDigit { character: 'z', index: 5 }),
+    /// );
+    /// ```
+    fn from_hex_str(s: &str) -> Result<Self, ParseHexError> {
+        let (rgb, _alpha) = parse(s)?;
+        Ok(Self::from_hex(rgb))
+    }
+}
+
+/// An error from parsing a hex string into a color.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ParseHexError {
+    /// The string did not have 3, 4, 6 or 8 digits.
+    InvalidLength(usize),
+    /// The string contained a character that is not a hex digit.
+    InvalidDigit {
+        /// The character.
+        character: char,
+        /// The byte index of the character in the string.
+        index: usize,
+    },
+}
+
+impl fmt::Display for ParseHexError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidLength(length) => write!(
+                f,
+                "hex color has {} digits, but must have 3, 4, 6 or 8",
+                length,
+            ),
+            Self::InvalidDigit { character, index } => {
+                write!(f, "invalid hex digit {:?} at index {}", character, index)
+            }
+        }
+    }
+}
+
+impl Error for ParseHexError {}
+
+/// Parses a hex string into its color as `0xRRGGBB` and its alpha, if any.
+pub(crate) fn parse(s: &str) -> Result<(u32, Option<u8>), ParseHexError> {
+    let offset = if s.starts_with('#') { 1 } else { 0 };
+
+    let digits = s[offset..]
+        .char_indices()
+        .map(|(index, character)| {
+            character.to_digit(16).ok_or(ParseHexError::InvalidDigit {
+                character,
+                index: index + offset,
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // Short forms repeat each digit, so `f80` is `ff8800`.
+    let bytes: Vec<u32> = match digits.len() {
+        3 | 4 => digits.iter().map(|digit| digit * 0x11).collect(),
+        6 | 8 => digits
+            .chunks(2)
+            .map(|pair| (pair[0] << 4) | pair[1])
+            .collect(),
+        length => return Err(ParseHexError::InvalidLength(length)),
+    };
+
+    let rgb = (bytes[0] << 16) | (bytes[1] << 8) | bytes[2];
+    let alpha = bytes.get(3).map(|&alpha| alpha as u8);
+
+    Ok((rgb, alpha))
+}
+
+pub(crate) fn to_byte<T: crate::Float>(n: T) -> u8 {
+    (n.to_f64() * 255.0).round() as u8
+}
+
+pub(crate) fn from_byte<T: crate::Float>(n: u8) -> T {
+    T::from_f64(f64::from(n) / 255.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_every_length() {
+        assert_eq!(parse("f80"), Ok((0xff8800, None)));
+        assert_eq!(parse("#f808"), Ok((0xff8800, Some(0x88))));
+        assert_eq!(parse("#ff80"), Ok((0xffff88, Some(0x00))));
+        assert_eq!(parse("#FF8000"), Ok((0xff8000, None)));
+        assert_eq!(parse("ff800080"), Ok((0xff8000, Some(0x80))));
+    }
+
+    #[test]
+    fn rejects_malformed_strings() {
+        assert_eq!(parse(""), Err(ParseHexError::InvalidLength(0)));
+        assert_eq!(parse("#"), Err(ParseHexError::InvalidLength(0)));
+        assert_eq!(parse("#ff800"), Err(ParseHexError::InvalidLength(5)));
+        assert_eq!(
+            parse("#ff 800"),
+            Err(ParseHexError::InvalidDigit {
+                character: ' ',
+                index: 3,
+            }),
+        );
+        assert_eq!(
+            parse("##fff"),
+            Err(ParseHexError::InvalidDigit {
+                character: '#',
+                index: 1,
+            }),
+        );
+    }
+
+    #[test]
+    fn implementable_with_only_components() {
+        struct Grey(f32);
+
+        impl Hex for Grey {
+            fn components(self) -> (f32, f32, f32) {
+                (self.0, self.0, self.0)
+            }
+        }
+
+        assert_eq!(Grey(0.5).hex(), 0x808080);
+        assert_eq!(Grey(1.0).hex_string(), "#ffffff");
+    }
 }
@@ -0,0 +1,88 @@
+/// A color from the HSL (hue, saturation, lightness) model of [`Srgb`](crate::Srgb).
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Hsl<T = f32> {
+    /// Hue.
+    pub h: crate::Hue<T>,
+    /// Saturation (0 to 1).
+    pub s: T,
+    /// Lightness.
+    /// 0 is complete black, 1 is the brightest white.
+    pub l: T,
+}
+
+impl<T: crate::Float> From<crate::Srgb<T>> for Hsl<T> {
+    fn from(srgb: crate::Srgb<T>) -> Self {
+        let max = srgb.r.max(srgb.g).max(srgb.b);
+        let min = srgb.r.min(srgb.g).min(srgb.b);
+
+        let l = (max + min) / T::from_f64(2.0);
+        let s = if l <= T::ZERO || l >= T::ONE {
+            T::ZERO
+        } else {
+            (max - l) / l.min(T::ONE - l)
+        };
+
+        Self {
+            h: crate::hue::hexagonal(srgb.r, srgb.g, srgb.b),
+            s,
+            l,
+        }
+    }
+}
+
+impl<T: crate::Float> crate::ColorSpace for Hsl<T> {
+    const BLACK: Self = Self {
+        h: crate::Hue::ZERO,
+        s: T::ZERO,
+        l: T::ZERO,
+    };
+
+    const WHITE: Self = Self {
+        h: crate::Hue::ZERO,
+        s: T::ZERO,
+        l: T::ONE,
+    };
+
+    fn in_bounds(self) -> bool {
+        crate::approx_in_range(self.s, T::ZERO..T::ONE)
+            && crate::approx_in_range(self.l, T::ZERO..T::ONE)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Hue, Srgb};
+
+    #[test]
+    fn round_trip() {
+        let srgb: Srgb = Srgb {
+            r: 0.2,
+            g: 0.6,
+            b: 0.4,
+        };
+
+        let hsl = Hsl::from(srgb);
+        assert!((hsl.h.to_degrees() - 150.0).abs() < 0.001);
+        assert!((hsl.s - 0.5).abs() < 0.001);
+        assert!((hsl.l - 0.4).abs() < 0.001);
+
+        let back = Srgb::from(hsl);
+        assert!((back.r - srgb.r).abs() < 0.001);
+        assert!((back.g - srgb.g).abs() < 0.001);
+        assert!((back.b - srgb.b).abs() < 0.001);
+    }
+
+    #[test]
+    fn achromatic() {
+        let hsl: Hsl = Hsl::from(Srgb {
+            r: 0.5,
+            g: 0.5,
+            b: 0.5,
+        });
+
+        assert_eq!(hsl.h, Hue::from_degrees(0.0).unwrap());
+        assert_eq!(hsl.s, 0.0);
+        assert_eq!(hsl.l, 0.5);
+    }
+}
@@ -0,0 +1,71 @@
+/// A color from the HSV (hue, saturation, value) model of [`Srgb`](crate::Srgb).
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Hsv<T = f32> {
+    /// Hue.
+    pub h: crate::Hue<T>,
+    /// Saturation (0 to 1).
+    pub s: T,
+    /// Value.
+    /// 0 is complete black, 1 is the brightest color of this hue and saturation.
+    pub v: T,
+}
+
+impl<T: crate::Float> From<crate::Srgb<T>> for Hsv<T> {
+    fn from(srgb: crate::Srgb<T>) -> Self {
+        let max = srgb.r.max(srgb.g).max(srgb.b);
+        let min = srgb.r.min(srgb.g).min(srgb.b);
+
+        let s = if max <= T::ZERO {
+            T::ZERO
+        } else {
+            (max - min) / max
+        };
+
+        Self {
+            h: crate::hue::hexagonal(srgb.r, srgb.g, srgb.b),
+            s,
+            v: max,
+        }
+    }
+}
+
+impl<T: crate::Float> crate::ColorSpace for Hsv<T> {
+    const BLACK: Self = Self {
+        h: crate::Hue::ZERO,
+        s: T::ZERO,
+        v: T::ZERO,
+    };
+
+    const WHITE: Self = Self {
+        h: crate::Hue::ZERO,
+        s: T::ZERO,
+        v: T::ONE,
+    };
+
+    fn in_bounds(self) -> bool {
+        crate::approx_in_range(self.s, T::ZERO..T::ONE)
+            && crate::approx_in_range(self.v, T::ZERO..T::ONE)
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn round_trip() {
+    use crate::Srgb;
+
+    let srgb: Srgb = Srgb {
+        r: 0.9,
+        g: 0.3,
+        b: 0.6,
+    };
+
+    let hsv = Hsv::from(srgb);
+    assert!((hsv.h.to_degrees() - 330.0).abs() < 0.001);
+    assert!((hsv.s - 2.0 / 3.0).abs() < 0.001);
+    assert!((hsv.v - 0.9).abs() < 0.001);
+
+    let back = Srgb::from(hsv);
+    assert!((back.r - srgb.r).abs() < 0.001);
+    assert!((back.g - srgb.g).abs() < 0.001);
+    assert!((back.b - srgb.b).abs() < 0.001);
+}
@@ -1,20 +1,22 @@
+use crate::Float;
+
 /// The hue of a color.
 #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
-pub struct Hue {
-    pub(crate) unnormalized_radians: f32,
+pub struct Hue<T = f32> {
+    pub(crate) unnormalized_radians: T,
 }
 
-impl Hue {
+impl<T: Float> Hue<T> {
     /// Creates a new `Hue` from a hue in degrees (from 0 to 360).
     ///
     /// Returns `None` if the input is not in range.
-    pub fn from_degrees(degrees: f32) -> Option<Self> {
-        if !(0.0..=360.0).contains(&degrees) {
+    pub fn from_degrees(degrees: T) -> Option<Self> {
+        if !(T::ZERO..=T::from_f64(360.0)).contains(&degrees) {
             return None;
         }
 
-        let unnormalized_degrees = if degrees > 180.0 {
-            degrees - 360.0
+        let unnormalized_degrees = if degrees > T::from_f64(180.0) {
+            degrees - T::from_f64(360.0)
         } else {
             degrees
         };
@@ -25,13 +27,51 @@ impl Hue {
     }
 
     /// The hue in degrees (from 0 to 360).
-    pub fn to_degrees(self) -> f32 {
+    pub fn to_degrees(self) -> T {
         let unnormalized_degrees = self.unnormalized_radians.to_degrees();
 
-        if unnormalized_degrees < 0.0 {
-            unnormalized_degrees + 360.0
+        if unnormalized_degrees < T::ZERO {
+            unnormalized_degrees + T::from_f64(360.0)
         } else {
             unnormalized_degrees
         }
     }
 }
+
+impl<T: Float> Hue<T> {
+    /// A hue of 0 degrees, used for colors without a meaningful hue.
+    pub(crate) const ZERO: Self = Self {
+        unnormalized_radians: T::ZERO,
+    };
+}
+
+/// Computes the hue of an RGB color on the hexagonal model used by HSL, HSV and HWB.
+///
+/// Achromatic colors have no meaningful hue, so they are given a hue of 0.
+pub(crate) fn hexagonal<T: Float>(r: T, g: T, b: T) -> Hue<T> {
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let chroma = max - min;
+
+    let sextant = if chroma == T::ZERO {
+        T::ZERO
+    } else if max == r {
+        ((g - b) / chroma).rem_euclid(T::from_f64(6.0))
+    } else if max == g {
+        (b - r) / chroma + T::from_f64(2.0)
+    } else {
+        (r - g) / chroma + T::from_f64(4.0)
+    };
+
+    let degrees = sextant * T::from_f64(60.0);
+
+    let unnormalized_degrees = if degrees > T::from_f64(180.0) {
+        degrees - T::from_f64(360.0)
+    } else {
+        degrees
+    };
+
+    Hue {
+        unnormalized_radians: unnormalized_degrees.to_radians(),
+    }
+}
@@ -0,0 +1,64 @@
+/// A color from the HWB (hue, whiteness, blackness) model of [`Srgb`](crate::Srgb).
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Hwb<T = f32> {
+    /// Hue.
+    pub h: crate::Hue<T>,
+    /// Whiteness, the amount of white mixed into the color (0 to 1).
+    pub w: T,
+    /// Blackness, the amount of black mixed into the color (0 to 1).
+    pub b: T,
+}
+
+impl<T: crate::Float> From<crate::Srgb<T>> for Hwb<T> {
+    fn from(srgb: crate::Srgb<T>) -> Self {
+        let max = srgb.r.max(srgb.g).max(srgb.b);
+        let min = srgb.r.min(srgb.g).min(srgb.b);
+
+        Self {
+            h: crate::hue::hexagonal(srgb.r, srgb.g, srgb.b),
+            w: min,
+            b: T::ONE - max,
+        }
+    }
+}
+
+impl<T: crate::Float> crate::ColorSpace for Hwb<T> {
+    const BLACK: Self = Self {
+        h: crate::Hue::ZERO,
+        w: T::ZERO,
+        b: T::ONE,
+    };
+
+    const WHITE: Self = Self {
+        h: crate::Hue::ZERO,
+        w: T::ONE,
+        b: T::ZERO,
+    };
+
+    fn in_bounds(self) -> bool {
+        crate::approx_in_range(self.w, T::ZERO..T::ONE)
+            && crate::approx_in_range(self.b, T::ZERO..T::ONE)
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn round_trip() {
+    use crate::Srgb;
+
+    let srgb: Srgb = Srgb {
+        r: 0.2,
+        g: 0.4,
+        b: 0.8,
+    };
+
+    let hwb = Hwb::from(srgb);
+    assert!((hwb.h.to_degrees() - 220.0).abs() < 0.001);
+    assert!((hwb.w - 0.2).abs() < 0.001);
+    assert!((hwb.b - 0.2).abs() < 0.001);
+
+    let back = Srgb::from(hwb);
+    assert!((back.r - srgb.r).abs() < 0.001);
+    assert!((back.g - srgb.g).abs() < 0.001);
+    assert!((back.b - srgb.b).abs() < 0.001);
+}
@@ -0,0 +1,83 @@
+//! The standard CIE illuminants for the 2° observer, usable as [`WhitePoint`](crate::WhitePoint)s.
+//!
+//! Tristimulus values are those published in ASTM E308.
+
+/// Incandescent (tungsten) light.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct A;
+
+impl crate::WhitePoint for A {
+    const XYZ: [f64; 3] = [1.09850, 1.0, 0.35585];
+}
+
+/// Average daylight (obsolete, superseded by D65).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct C;
+
+impl crate::WhitePoint for C {
+    const XYZ: [f64; 3] = [0.98074, 1.0, 1.18232];
+}
+
+/// Horizon daylight, used by ICC profiles and printing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct D50;
+
+impl crate::WhitePoint for D50 {
+    const XYZ: [f64; 3] = [0.96422, 1.0, 0.82521];
+}
+
+/// Mid-morning or mid-afternoon daylight.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct D55;
+
+impl crate::WhitePoint for D55 {
+    const XYZ: [f64; 3] = [0.95682, 1.0, 0.92149];
+}
+
+/// Noon daylight, used by sRGB and most displays.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct D65;
+
+impl crate::WhitePoint for D65 {
+    const XYZ: [f64; 3] = [0.95047, 1.0, 1.08883];
+}
+
+/// North sky daylight.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct D75;
+
+impl crate::WhitePoint for D75 {
+    const XYZ: [f64; 3] = [0.94972, 1.0, 1.22638];
+}
+
+/// The equal-energy illuminant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct E;
+
+impl crate::WhitePoint for E {
+    const XYZ: [f64; 3] = [1.0, 1.0, 1.0];
+}
+
+/// Cool white fluorescent light.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct F2;
+
+impl crate::WhitePoint for F2 {
+    const XYZ: [f64; 3] = [0.99187, 1.0, 0.67395];
+}
+
+/// Broadband daylight fluorescent light.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct F7;
+
+impl crate::WhitePoint for F7 {
+    const XYZ: [f64; 3] = [0.95044, 1.0, 1.08755];
+}
+
+/// Narrow tri-band fluorescent light.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct F11;
+
+impl crate::WhitePoint for F11 {
+    const XYZ: [f64; 3] = [1.00966, 1.0, 0.64370];
+}
@@ -0,0 +1,489 @@
+use crate::{Float, Hue};
+
+/// A color that can be interpolated with another color of the same color space,
+/// like CSS’s `color-mix()`.
+///
+/// Rectangular color spaces interpolate each component linearly.
+/// Polar color spaces interpolate their hue along the arc chosen by a [`HueInterpolation`].
+/// Interpolating in [`Oklab`](crate::Oklab) or [`Oklch`](crate::Oklch) usually gives the most even results:
+///
+/// ```
+/// use tincture::{Interpolate, Oklab};
+///
+/// let black: Oklab = Oklab { l: 0.0, a: 0.0, b: 0.0 };
+/// let white: Oklab = Oklab { l: 1.0, a: 0.0, b: 0.0 };
+///
+/// assert_eq!(black.interpolate(white, 0.25), Oklab { l: 0.25, a: 0.0, b: 0.0 });
+/// ```
+pub trait Interpolate<T = f32>: Sized {
+    /// Interpolates between `self` at a `t` of 0 and `other` at a `t` of 1,
+    /// taking the [shorter](HueInterpolation::Shorter) arc between hues.
+    ///
+    /// Values of `t` outside 0 to 1 extrapolate.
+    fn interpolate(self, other: Self, t: T) -> Self {
+        self.interpolate_with(other, t, HueInterpolation::Shorter)
+    }
+
+    /// Interpolates between `self` at a `t` of 0 and `other` at a `t` of 1,
+    /// taking the arc between hues chosen by `hue`.
+    ///
+    /// `hue` is ignored by color spaces without a hue.
+    ///
+    /// If one of the colors is achromatic its hue is powerless,
+    /// so the hue of the other color is used throughout, as in CSS.
+    ///
+    /// ```
+    /// use tincture::{Hue, HueInterpolation, Interpolate, Oklch};
+    ///
+    /// let red: Oklch = Oklch { l: 0.6, c: 0.2, h: Hue::from_degrees(30.0).unwrap() };
+    /// let blue: Oklch = Oklch { l: 0.6, c: 0.2, h: Hue::from_degrees(270.0).unwrap() };
+    ///
+    /// let shorter = red.interpolate_with(blue, 0.5, HueInterpolation::Shorter);
+    /// assert!((shorter.h.to_degrees() - 330.0).abs() < 0.001);
+    ///
+    /// let longer = red.interpolate_with(blue, 0.5, HueInterpolation::Longer);
+    /// assert!((longer.h.to_degrees() - 150.0).abs() < 0.001);
+    /// ```
+    fn interpolate_with(self, other: Self, t: T, hue: HueInterpolation) -> Self;
+}
+
+/// Which way around the hue wheel to interpolate between two hues,
+/// as in [CSS Color 4](https://www.w3.org/TR/css-color-4/#hue-interpolation).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum HueInterpolation {
+    /// The arc of at most 180 degrees.
+    #[default]
+    Shorter,
+    /// The arc of at least 180 degrees.
+    Longer,
+    /// The arc along which the hue increases.
+    Increasing,
+    /// The arc along which the hue decreases.
+    Decreasing,
+}
+
+impl HueInterpolation {
+    /// Interpolates between two hues, either of which is `None` if it is missing.
+    fn interpolate<T: Float>(self, a: Option<Hue<T>>, b: Option<Hue<T>>, t: T) -> Hue<T> {
+        let (a, b) = match (a, b) {
+            (Some(a), Some(b)) => (a.to_degrees(), b.to_degrees()),
+            (Some(hue), None) | (None, Some(hue)) => return hue,
+            (None, None) => return Hue::ZERO,
+        };
+
+        let half_turn = T::from_f64(180.0);
+        let turn = T::from_f64(360.0);
+        let difference = b - a;
+
+        let (a, b) = match self {
+            Self::Shorter if difference > half_turn => (a + turn, b),
+            Self::Shorter if difference < -half_turn => (a, b + turn),
+            Self::Longer if T::ZERO < difference && difference < half_turn => (a + turn, b),
+            Self::Longer if -half_turn < difference && difference <= T::ZERO => (a, b + turn),
+            Self::Increasing if b < a => (a, b + turn),
+            Self::Decreasing if a < b => (a + turn, b),
+            _ => (a, b),
+        };
+
+        Hue::from_degrees(lerp(a, b, t).rem_euclid(turn)).unwrap_or(Hue::ZERO)
+    }
+}
+
+pub(crate) fn lerp<T: Float>(a: T, b: T, t: T) -> T {
+    a + (b - a) * t
+}
+
+macro_rules! impl_rectangular {
+    ($ty:ty, [$($param:ident: $bound:path),*], $c0:ident, $c1:ident, $c2:ident, $construct:expr) => {
+        impl<$($param: $bound,)* T: Float> Interpolate<T> for $ty {
+            fn interpolate_with(self, other: Self, t: T, _hue: HueInterpolation) -> Self {
+                let $c0 = lerp(self.$c0, other.$c0, t);
+                let $c1 = lerp(self.$c1, other.$c1, t);
+                let $c2 = lerp(self.$c2, other.$c2, t);
+                $construct
+            }
+        }
+    };
+}
+
+impl_rectangular!(crate::Xyz<W, T>, [W: crate::WhitePoint], x, y, z, Self::new(x, y, z));
+impl_rectangular!(crate::Lab<W, T>, [W: crate::WhitePoint], l, a, b, Self::new(l, a, b));
+impl_rectangular!(crate::Luv<W, T>, [W: crate::WhitePoint], l, u, v, Self::new(l, u, v));
+impl_rectangular!(crate::LinearRgbIn<S, T>, [S: crate::RgbSpaceDefinition], r, g, b, Self::new(r, g, b));
+impl_rectangular!(crate::EncodedRgbIn<S, T>, [S: crate::RgbSpaceDefinition], r, g, b, Self::new(r, g, b));
+impl_rectangular!(crate::LinearRgb<T>, [], r, g, b, Self { r, g, b });
+impl_rectangular!(crate::Srgb<T>, [], r, g, b, Self { r, g, b });
+impl_rectangular!(crate::Oklab<T>, [], l, a, b, Self { l, a, b });
+
+/// The chroma below which the hue of an [`Oklch`](crate::Oklch) is powerless.
+///
+/// This is large enough to include the greys of the RGB color spaces,
+/// which have a chroma of up to about 0.0001 after rounding.
+const OKLCH_ACHROMATIC: f64 = 0.0002;
+
+/// The chroma below which the hue of an [`Lch`](crate::Lch) or [`Lchuv`](crate::Lchuv) is powerless.
+const LCH_ACHROMATIC: f64 = 0.0015;
+
+/// The saturation below which the hue of a color from a model such as [`Hsl`](crate::Hsl) is powerless.
+const SATURATION_ACHROMATIC: f64 = 0.000001;
+
+/// `hue`, or `None` if `powerless`.
+fn hue<T: Float>(hue: Hue<T>, powerless: bool) -> Option<Hue<T>> {
+    if powerless {
+        None
+    } else {
+        Some(hue)
+    }
+}
+
+impl<T: Float> Interpolate<T> for crate::Oklch<T> {
+    fn interpolate_with(self, other: Self, t: T, method: HueInterpolation) -> Self {
+        let achromatic = T::from_f64(OKLCH_ACHROMATIC);
+
+        Self {
+            l: lerp(self.l, other.l, t),
+            c: lerp(self.c, other.c, t),
+            h: method.interpolate(
+                hue(self.h, self.c < achromatic),
+                hue(other.h, other.c < achromatic),
+                t,
+            ),
+        }
+    }
+}
+
+impl<W: crate::WhitePoint, T: Float> Interpolate<T> for crate::Lch<W, T> {
+    fn interpolate_with(self, other: Self, t: T, method: HueInterpolation) -> Self {
+        let achromatic = T::from_f64(LCH_ACHROMATIC);
+
+        Self::new(
+            lerp(self.l, other.l, t),
+            lerp(self.c, other.c, t),
+            method.interpolate(
+                hue(self.h, self.c < achromatic),
+                hue(other.h, other.c < achromatic),
+                t,
+            ),
+        )
+    }
+}
+
+impl<W: crate::WhitePoint, T: Float> Interpolate<T> for crate::Lchuv<W, T> {
+    fn interpolate_with(self, other: Self, t: T, method: HueInterpolation) -> Self {
+        let achromatic = T::from_f64(LCH_ACHROMATIC);
+
+        Self::new(
+            lerp(self.l, other.l, t),
+            lerp(self.c, other.c, t),
+            method.interpolate(
+                hue(self.h, self.c < achromatic),
+                hue(other.h, other.c < achromatic),
+                t,
+            ),
+        )
+    }
+}
+
+impl<T: Float> Interpolate<T> for crate::Hsl<T> {
+    fn interpolate_with(self, other: Self, t: T, method: HueInterpolation) -> Self {
+        let achromatic = T::from_f64(SATURATION_ACHROMATIC);
+
+        Self {
+            h: method.interpolate(
+                hue(self.h, self.s < achromatic),
+                hue(other.h, other.s < achromatic),
+                t,
+            ),
+            s: lerp(self.s, other.s, t),
+            l: lerp(self.l, other.l, t),
+        }
+    }
+}
+
+impl<T: Float> Interpolate<T> for crate::Hsv<T> {
+    fn interpolate_with(self, other: Self, t: T, method: HueInterpolation) -> Self {
+        let achromatic = T::from_f64(SATURATION_ACHROMATIC);
+
+        Self {
+            h: method.interpolate(
+                hue(self.h, self.s < achromatic),
+                hue(other.h, other.s < achromatic),
+                t,
+            ),
+            s: lerp(self.s, other.s, t),
+            v: lerp(self.v, other.v, t),
+        }
+    }
+}
+
+impl<T: Float> Interpolate<T> for crate::Hwb<T> {
+    fn interpolate_with(self, other: Self, t: T, method: HueInterpolation) -> Self {
+        // Whiteness and blackness adding up to 1 leave no room for the hue.
+        let achromatic = T::ONE - T::from_f64(SATURATION_ACHROMATIC);
+
+        Self {
+            h: method.interpolate(
+                hue(self.h, self.w + self.b > achromatic),
+                hue(other.h, other.w + other.b > achromatic),
+                t,
+            ),
+            w: lerp(self.w, other.w, t),
+            b: lerp(self.b, other.b, t),
+        }
+    }
+}
+
+impl<T: Float> Interpolate<T> for crate::Okhsv<T> {
+    fn interpolate_with(self, other: Self, t: T, method: HueInterpolation) -> Self {
+        let achromatic = T::from_f64(SATURATION_ACHROMATIC);
+
+        Self {
+            h: method.interpolate(
+                hue(self.h, self.s < achromatic),
+                hue(other.h, other.s < achromatic),
+                t,
+            ),
+            s: lerp(self.s, other.s, t),
+            v: lerp(self.v, other.v, t),
+        }
+    }
+}
+
+impl<T: Float> Interpolate<T> for crate::Okhsl<T> {
+    fn interpolate_with(self, other: Self, t: T, method: HueInterpolation) -> Self {
+        let achromatic = T::from_f64(SATURATION_ACHROMATIC);
+
+        Self {
+            h: method.interpolate(
+                hue(self.h, self.s < achromatic),
+                hue(other.h, other.s < achromatic),
+                t,
+            ),
+            s: lerp(self.s, other.s, t),
+            l: lerp(self.l, other.l, t),
+        }
+    }
+}
+
+impl<T: Float> Interpolate<T> for crate::css::Color<T> {
+    /// Interpolates between colors as [CSS Color 4](https://www.w3.org/TR/css-color-4/#interpolation) does,
+    /// in the color space of `self`.
+    ///
+    /// `other` is converted with [`to_space`](crate::css::Color::to_space).
+    /// A missing component takes the value of the same component of the other color,
+    /// and stays missing if it is missing from both.
+    /// Every component except hue is premultiplied by alpha.
+    ///
+    /// ```
+    /// use tincture::{css, Interpolate};
+    ///
+    /// let a: css::Color<f64> = css::parse("oklch(0.5 none 120)").unwrap();
+    /// let b: css::Color<f64> = css::parse("oklch(0.7 0.2 none / 50%)").unwrap();
+    /// let mixed = a.interpolate(b, 0.5);
+    ///
+    /// assert_eq!(mixed.to_string(), "oklch(0.56667 0.2 120 / 0.75)");
+    /// ```
+    fn interpolate_with(self, other: Self, t: T, method: HueInterpolation) -> Self {
+        let other = other.to_space(self.space);
+        let hue_index = self.space.hue_index();
+
+        // Fill in missing components from the other color first,
+        // so that a missing chroma does not make a hue powerless.
+        let fill = |color: Self, from: Self| Self {
+            components: [0, 1, 2].map(|i| color.components[i].or(from.components[i])),
+            alpha: color.alpha.or(from.alpha),
+            ..color
+        };
+        let (a, b) = (fill(self, other), fill(other, self));
+
+        let alpha = match (a.alpha, b.alpha) {
+            (Some(a), Some(b)) => Some(lerp(a, b, t)),
+            _ => None,
+        };
+
+        let mut components = [None; 3];
+        for (index, component) in components.iter_mut().enumerate() {
+            *component = match (a.components[index], b.components[index]) {
+                (Some(_), Some(_)) if Some(index) == hue_index => {
+                    Some(method.interpolate(css_hue(a), css_hue(b), t).to_degrees())
+                }
+                (Some(c0), Some(c1)) => {
+                    let premultiplied = lerp(
+                        c0 * a.alpha.unwrap_or(T::ONE),
+                        c1 * b.alpha.unwrap_or(T::ONE),
+                        t,
+                    );
+
+                    Some(match alpha {
+                        Some(alpha) if alpha != T::ZERO => premultiplied / alpha,
+                        _ => premultiplied,
+                    })
+                }
+                _ => None,
+            };
+        }
+
+        Self {
+            space: self.space,
+            components,
+            alpha,
+        }
+    }
+}
+
+/// The hue of a CSS color, or `None` if it is powerless.
+fn css_hue<T: Float>(color: crate::css::Color<T>) -> Option<Hue<T>> {
+    use crate::css::Space;
+
+    let [c0, c1, c2] = color.components.map(|c| c.unwrap_or(T::ZERO));
+
+    let (degrees, powerless) = match color.space {
+        Space::Hsl => (c0, c1 < T::from_f64(SATURATION_ACHROMATIC)),
+        Space::Hwb => (c0, c1 + c2 > T::ONE - T::from_f64(SATURATION_ACHROMATIC)),
+        Space::Lch => (c2, c1 < T::from_f64(LCH_ACHROMATIC)),
+        Space::Oklch => (c2, c1 < T::from_f64(OKLCH_ACHROMATIC)),
+        _ => return None,
+    };
+
+    let turn = T::from_f64(360.0);
+    hue(
+        Hue::from_degrees(degrees.rem_euclid(turn)).unwrap_or(Hue::ZERO),
+        powerless,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ColorSpace, Hsl, LinearRgb, Oklab, Oklch, Srgb};
+
+    fn hue(degrees: f64) -> Hue<f64> {
+        Hue::from_degrees(degrees).unwrap()
+    }
+
+    fn interpolate_hue(a: f64, b: f64, t: f64, method: HueInterpolation) -> f64 {
+        method
+            .interpolate(Some(hue(a)), Some(hue(b)), t)
+            .to_degrees()
+    }
+
+    #[test]
+    fn hue_methods() {
+        let cases = [
+            (HueInterpolation::Shorter, 30.0, 330.0, 0.0),
+            (HueInterpolation::Shorter, 330.0, 30.0, 0.0),
+            (HueInterpolation::Shorter, 30.0, 90.0, 60.0),
+            (HueInterpolation::Longer, 30.0, 90.0, 240.0),
+            (HueInterpolation::Longer, 30.0, 330.0, 180.0),
+            (HueInterpolation::Increasing, 330.0, 30.0, 0.0),
+            (HueInterpolation::Increasing, 30.0, 330.0, 180.0),
+            (HueInterpolation::Decreasing, 30.0, 330.0, 0.0),
+            (HueInterpolation::Decreasing, 330.0, 30.0, 180.0),
+        ];
+
+        for &(method, a, b, expected) in &cases {
+            let actual = interpolate_hue(a, b, 0.5, method);
+            assert!(
+                (actual - expected).abs() < 1e-9 || (actual - expected).abs() > 360.0 - 1e-9,
+                "{:?} from {} to {} gave {}",
+                method,
+                a,
+                b,
+                actual,
+            );
+        }
+    }
+
+    #[test]
+    fn endpoints() {
+        let a = Oklch {
+            l: 0.4,
+            c: 0.1,
+            h: hue(300.0),
+        };
+        let b = Oklch {
+            l: 0.8,
+            c: 0.2,
+            h: hue(20.0),
+        };
+
+        for &method in &[
+            HueInterpolation::Shorter,
+            HueInterpolation::Longer,
+            HueInterpolation::Increasing,
+            HueInterpolation::Decreasing,
+        ] {
+            assert!((a.interpolate_with(b, 0.0, method).h.to_degrees() - 300.0).abs() < 1e-9);
+            assert!((a.interpolate_with(b, 1.0, method).h.to_degrees() - 20.0).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn powerless_hue_takes_other_hue() {
+        let white = Oklch::from(crate::convert::<_, Oklab<f64>>(LinearRgb::WHITE));
+        let blue = Oklch {
+            l: 0.5,
+            c: 0.2,
+            h: hue(260.0),
+        };
+
+        let mixed = white.interpolate(blue, 0.5);
+        assert!((mixed.h.to_degrees() - 260.0).abs() < 1e-9);
+        assert!((mixed.c - 0.1).abs() < 1e-3);
+
+        let grey: Hsl<f64> = Hsl::from(Srgb {
+            r: 0.5,
+            g: 0.5,
+            b: 0.5,
+        });
+        let green = Hsl {
+            h: hue(120.0),
+            s: 1.0,
+            l: 0.5,
+        };
+
+        assert!((grey.interpolate(green, 0.25).h.to_degrees() - 120.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn rectangular() {
+        let mixed = Srgb::<f64>::BLACK.interpolate(Srgb::WHITE, 0.25);
+        assert_eq!(
+            mixed,
+            Srgb {
+                r: 0.25,
+                g: 0.25,
+                b: 0.25,
+            },
+        );
+    }
+
+    #[test]
+    fn css_missing_components() {
+        let parse = |s: &str| crate::css::parse::<f64>(s).unwrap();
+
+        let mixed = parse("rgb(none 0 255 / none)").interpolate(parse("rgb(255 none 0)"), 0.25);
+        assert_eq!(mixed.components, [Some(1.0), Some(0.0), Some(0.75)]);
+        assert_eq!(mixed.alpha, Some(1.0));
+
+        let mixed = parse("lab(none 10 none)").interpolate(parse("lab(50 30 none)"), 0.5);
+        assert_eq!(mixed.components, [Some(50.0), Some(20.0), None]);
+
+        let mixed = parse("hsl(none 0% 50%)").interpolate(parse("hsl(120 100% 50%)"), 0.5);
+        assert!((mixed.components[0].unwrap() - 120.0).abs() < 1e-9);
+
+        let mixed = parse("oklch(none 0.1 none)").interpolate(parse("lch(none 30 none)"), 0.5);
+        assert_eq!(mixed.components[0], None);
+        assert!(mixed.components[1].is_some());
+        assert_eq!(mixed.components[2], None);
+    }
+
+    #[test]
+    fn css_premultiplies() {
+        let parse = |s: &str| crate::css::parse::<f64>(s).unwrap();
+
+        let mixed = parse("rgb(255 0 0 / 0)").interpolate(parse("rgb(0 0 255)"), 0.5);
+        assert_eq!(mixed.components, [Some(0.0), Some(0.0), Some(1.0)]);
+        assert_eq!(mixed.alpha, Some(0.5));
+    }
+}
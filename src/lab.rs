@@ -0,0 +1,132 @@
+use crate::float::const_from_f64;
+use std::marker::PhantomData;
+
+/// A color from the [CIELAB] color space (also known as CIE L\*a\*b\*), relative to the white point `W`.
+///
+/// [CIELAB]: https://en.wikipedia.org/wiki/CIELAB_color_space
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct Lab<W = crate::illuminant::D65, T = f32> {
+    /// Lightness.
+    /// 0 is complete black, 100 is the brightest white.
+    pub l: T,
+    /// Green vs red.
+    /// -125 is green, 125 is red.
+    pub a: T,
+    /// Blue vs yellow.
+    /// -125 is blue, 125 is yellow.
+    pub b: T,
+    white_point: PhantomData<W>,
+}
+
+impl<W, T> Lab<W, T> {
+    /// Creates a new color from its components.
+    pub const fn new(l: T, a: T, b: T) -> Self {
+        Self {
+            l,
+            a,
+            b,
+            white_point: PhantomData,
+        }
+    }
+}
+
+const DELTA: f64 = 6.0 / 29.0;
+
+fn f<T: crate::Float>(t: T) -> T {
+    if t > T::from_f64(DELTA.powi(3)) {
+        t.cbrt()
+    } else {
+        t / T::from_f64(3.0 * DELTA.powi(2)) + T::from_f64(4.0 / 29.0)
+    }
+}
+
+fn f_inv<T: crate::Float>(t: T) -> T {
+    if t > T::from_f64(DELTA) {
+        t.powi(3)
+    } else {
+        T::from_f64(3.0 * DELTA.powi(2)) * (t - T::from_f64(4.0 / 29.0))
+    }
+}
+
+impl<W, T: crate::Float> From<crate::Lch<W, T>> for Lab<W, T> {
+    fn from(lch: crate::Lch<W, T>) -> Self {
+        Self::new(
+            lch.l,
+            lch.c * lch.h.unnormalized_radians.cos(),
+            lch.c * lch.h.unnormalized_radians.sin(),
+        )
+    }
+}
+
+impl<W, T: crate::Float> crate::ColorSpace for Lab<W, T> {
+    const BLACK: Self = Self::new(T::ZERO, T::ZERO, T::ZERO);
+
+    const WHITE: Self = Self::new(const_from_f64(100.0), T::ZERO, T::ZERO);
+
+    fn in_bounds(self) -> bool {
+        crate::approx_in_range(self.l, T::ZERO..T::from_f64(100.0))
+            && crate::approx_in_range(self.a, T::from_f64(-125.0)..T::from_f64(125.0))
+            && crate::approx_in_range(self.b, T::from_f64(-125.0)..T::from_f64(125.0))
+    }
+}
+
+impl<W: crate::WhitePoint, T: crate::Float> crate::CoreColorSpace for Lab<W, T> {
+    type WhitePoint = W;
+    type Component = T;
+
+    fn from_xyz(xyz: crate::Xyz<W, T>) -> Self {
+        let fx = f(xyz.x / T::from_f64(W::XYZ[0]));
+        let fy = f(xyz.y / T::from_f64(W::XYZ[1]));
+        let fz = f(xyz.z / T::from_f64(W::XYZ[2]));
+
+        Self::new(
+            T::from_f64(116.0) * fy - T::from_f64(16.0),
+            T::from_f64(500.0) * (fx - fy),
+            T::from_f64(200.0) * (fy - fz),
+        )
+    }
+
+    fn to_xyz(self) -> crate::Xyz<W, T> {
+        let fy = (self.l + T::from_f64(16.0)) / T::from_f64(116.0);
+        let fx = fy + self.a / T::from_f64(500.0);
+        let fz = fy - self.b / T::from_f64(200.0);
+
+        crate::Xyz::new(
+            T::from_f64(W::XYZ[0]) * f_inv(fx),
+            T::from_f64(W::XYZ[1]) * f_inv(fy),
+            T::from_f64(W::XYZ[2]) * f_inv(fz),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::illuminant::D50;
+    use crate::LinearRgb;
+
+    const RED: LinearRgb = LinearRgb {
+        r: 1.0,
+        g: 0.0,
+        b: 0.0,
+    };
+
+    #[test]
+    fn red_d65() {
+        let lab: Lab = crate::convert(RED);
+
+        assert!((lab.l - 53.2408).abs() < 0.001);
+        assert!((lab.a - 80.0925).abs() < 0.001);
+        assert!((lab.b - 67.2032).abs() < 0.001);
+    }
+
+    #[test]
+    fn red_d50() {
+        let lab: Lab<D50> = crate::convert(RED);
+
+        assert!((lab.l - 54.29).abs() < 0.05);
+        assert!((lab.a - 80.80).abs() < 0.05);
+        assert!((lab.b - 69.89).abs() < 0.05);
+    }
+}
@@ -0,0 +1,55 @@
+use crate::float::const_from_f64;
+use std::marker::PhantomData;
+
+/// A color from the polar variant of the [CIELAB] color space (also known as CIE LCh(ab)), relative to the white point `W`.
+///
+/// [CIELAB]: https://en.wikipedia.org/wiki/CIELAB_color_space
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Lch<W = crate::illuminant::D65, T = f32> {
+    /// Lightness.
+    /// 0 is complete black, 100 is the brightest white.
+    pub l: T,
+    /// Chroma, which is similar to ([but not exactly the same as][chroma_vs_sat]) saturation.
+    /// 0 is completely colorless, 150 is the most vivid color.
+    ///
+    /// [chroma_vs_sat]: https://munsell.com/color-blog/difference-chroma-saturation/
+    pub c: T,
+    /// Hue.
+    pub h: crate::Hue<T>,
+    white_point: PhantomData<W>,
+}
+
+impl<W, T> Lch<W, T> {
+    /// Creates a new color from its components.
+    pub const fn new(l: T, c: T, h: crate::Hue<T>) -> Self {
+        Self {
+            l,
+            c,
+            h,
+            white_point: PhantomData,
+        }
+    }
+}
+
+impl<W, T: crate::Float> From<crate::Lab<W, T>> for Lch<W, T> {
+    fn from(lab: crate::Lab<W, T>) -> Self {
+        Self::new(
+            lab.l,
+            (lab.a.powi(2) + lab.b.powi(2)).sqrt(),
+            crate::Hue {
+                unnormalized_radians: lab.b.atan2(lab.a),
+            },
+        )
+    }
+}
+
+impl<W, T: crate::Float> crate::ColorSpace for Lch<W, T> {
+    const BLACK: Self = Self::new(T::ZERO, T::ZERO, crate::Hue::ZERO);
+
+    const WHITE: Self = Self::new(const_from_f64(100.0), T::ZERO, crate::Hue::ZERO);
+
+    fn in_bounds(self) -> bool {
+        crate::approx_in_range(self.l, T::ZERO..T::from_f64(100.0))
+            && crate::approx_in_range(self.c, T::ZERO..T::from_f64(150.0))
+    }
+}
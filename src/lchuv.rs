@@ -0,0 +1,55 @@
+use crate::float::const_from_f64;
+use std::marker::PhantomData;
+
+/// A color from the polar variant of the [CIELUV] color space (also known as CIE LCh(uv)), relative to the white point `W`.
+///
+/// [CIELUV]: https://en.wikipedia.org/wiki/CIELUV
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Lchuv<W = crate::illuminant::D65, T = f32> {
+    /// Lightness.
+    /// 0 is complete black, 100 is the brightest white.
+    pub l: T,
+    /// Chroma, which is similar to ([but not exactly the same as][chroma_vs_sat]) saturation.
+    /// 0 is completely colorless, 180 is the most vivid color.
+    ///
+    /// [chroma_vs_sat]: https://munsell.com/color-blog/difference-chroma-saturation/
+    pub c: T,
+    /// Hue.
+    pub h: crate::Hue<T>,
+    white_point: PhantomData<W>,
+}
+
+impl<W, T> Lchuv<W, T> {
+    /// Creates a new color from its components.
+    pub const fn new(l: T, c: T, h: crate::Hue<T>) -> Self {
+        Self {
+            l,
+            c,
+            h,
+            white_point: PhantomData,
+        }
+    }
+}
+
+impl<W, T: crate::Float> From<crate::Luv<W, T>> for Lchuv<W, T> {
+    fn from(luv: crate::Luv<W, T>) -> Self {
+        Self::new(
+            luv.l,
+            (luv.u.powi(2) + luv.v.powi(2)).sqrt(),
+            crate::Hue {
+                unnormalized_radians: luv.v.atan2(luv.u),
+            },
+        )
+    }
+}
+
+impl<W, T: crate::Float> crate::ColorSpace for Lchuv<W, T> {
+    const BLACK: Self = Self::new(T::ZERO, T::ZERO, crate::Hue::ZERO);
+
+    const WHITE: Self = Self::new(const_from_f64(100.0), T::ZERO, crate::Hue::ZERO);
+
+    fn in_bounds(self) -> bool {
+        crate::approx_in_range(self.l, T::ZERO..T::from_f64(100.0))
+            && crate::approx_in_range(self.c, T::ZERO..T::from_f64(180.0))
+    }
+}
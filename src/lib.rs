@@ -5,13 +5,13 @@
 //! ```
 //! use tincture::{LinearRgb, Oklab};
 //!
-//! let rebeccapurple = LinearRgb {
+//! let purple = LinearRgb {
 //!     r: 0.4,
 //!     g: 0.2,
 //!     b: 0.6,
 //! };
 //!
-//! let oklab: Oklab = tincture::convert(rebeccapurple);
+//! let oklab: Oklab = tincture::convert(purple);
 //!
 //! assert_eq!(
 //!     oklab,
@@ -25,7 +25,7 @@
 //!
 //! Variations on the core color spaces do not implement [`CoreColorSpace`], which is necessary for [`convert`].
 //! Instead, they implement `From<ACoreColorSpace>`, allowing you to convert this variation to its corresponding core color space and call [`convert`].
-//! Examples of variations include [`Oklch`] (a variation on [`Oklab`]) and [`Srgb`] (a variation on [`LinearRgb`]).
+//! Examples of variations include [`Oklch`] (a variation on [`Oklab`]), [`Lch`] (a variation on [`Lab`]), [`Srgb`] (a variation on [`LinearRgb`]) and [`Hsl`] (a variation on [`Srgb`]).
 //!
 //! ```
 //! use tincture::{Hue, LinearRgb, Oklab, Oklch, Srgb};
@@ -47,6 +47,15 @@
 //! let srgb = Srgb::from(linear_rgb);
 //! ```
 //!
+//! Every color type is generic over the type of its components, which defaults to `f32`.
+//! Use `f64` when the extra precision is needed:
+//!
+//! ```
+//! use tincture::{LinearRgb, Oklab};
+//!
+//! let oklab: Oklab<f64> = tincture::convert(LinearRgb { r: 0.4, g: 0.2, b: 0.6 });
+//! ```
+//!
 //! _All_ color spaces implement [`ColorSpace`], which provides the constants `BLACK` and `WHITE`:
 //!
 //! ```
@@ -80,29 +89,139 @@
 #![warn(missing_debug_implementations, missing_docs, rust_2018_idioms)]
 #![allow(clippy::excessive_precision)]
 
+pub mod apca;
+pub mod batch;
+pub mod compositing;
+pub mod contrast;
+pub mod css;
+pub mod difference;
+pub mod gradient;
+pub mod illuminant;
+pub mod named;
+pub mod wcag;
+
+mod adobe_rgb;
+mod alpha;
+mod chromatic_adaptation;
+mod chromaticity;
+mod converter;
+mod display_p3;
+mod encoded_rgb_in;
+mod float;
+mod gamut_mapping;
 mod hex;
+mod hsl;
+mod hsv;
 mod hue;
+mod hwb;
+mod interpolate;
+mod lab;
+mod lch;
+mod lchuv;
 mod linear_rgb;
+mod linear_rgb_in;
+mod luv;
+mod matrix;
+mod okhsl;
+mod okhsv;
 mod oklab;
 mod oklch;
+mod pro_photo_rgb;
+mod rec2020;
+mod rgb_space;
 mod srgb;
+mod srgb_gamut;
+mod transfer;
+mod white_point;
 mod xyz;
 
-pub use hex::Hex;
+pub use adobe_rgb::{AdobeRgb, AdobeRgbSpace, LinearAdobeRgb};
+pub use alpha::{Alpha, PremultipliedAlpha, Premultiply};
+pub use chromatic_adaptation::ChromaticAdaptation;
+pub use chromaticity::Chromaticity;
+pub use converter::Converter;
+pub use display_p3::{DisplayP3, DisplayP3Space, LinearDisplayP3};
+pub use encoded_rgb_in::EncodedRgbIn;
+pub use float::Float;
+pub use gamut_mapping::{gamut_map, GamutMapping, RgbGamut};
+pub use hex::{FromHex, Hex, ParseHexError};
+pub use hsl::Hsl;
+pub use hsv::Hsv;
 pub use hue::Hue;
+pub use hwb::Hwb;
+pub use interpolate::{HueInterpolation, Interpolate};
+pub use lab::Lab;
+pub use lch::Lch;
+pub use lchuv::Lchuv;
 pub use linear_rgb::LinearRgb;
+pub use linear_rgb_in::LinearRgbIn;
+pub use luv::Luv;
+pub use okhsl::Okhsl;
+pub use okhsv::Okhsv;
 pub use oklab::Oklab;
 pub use oklch::Oklch;
+pub use pro_photo_rgb::{LinearProPhotoRgb, ProPhotoRgb, ProPhotoRgbSpace};
+pub use rec2020::{LinearRec2020, Rec2020, Rec2020Space};
+pub use rgb_space::{RgbSpace, RgbSpaceDefinition, TransferFunction};
 pub use srgb::Srgb;
+pub use srgb_gamut::Cusp;
+pub use white_point::WhitePoint;
 pub use xyz::Xyz;
 
 /// A color space that can be converted to any other `CoreColorSpace`.
 pub trait CoreColorSpace {
+    /// The white point that the color space is defined relative to.
+    type WhitePoint: WhitePoint;
+
+    /// The type of the components of the color.
+    type Component: Float;
+
     /// Convert a color in the XYZ color space to the color space that `Self` represents.
-    fn from_xyz(xyz: Xyz) -> Self;
+    fn from_xyz(xyz: Xyz<Self::WhitePoint, Self::Component>) -> Self;
 
     /// Convert the color of `Self` to the XYZ color space.
-    fn to_xyz(self) -> Xyz;
+    fn to_xyz(self) -> Xyz<Self::WhitePoint, Self::Component>;
+
+    /// The matrix converting the linear stage of the color space to XYZ.
+    ///
+    /// Many color spaces are a matrix multiplication away from XYZ,
+    /// possibly followed by a nonlinear step:
+    /// [`LinearRgb`] is itself linear, and [`Oklab`] applies a cube root to linear cone responses.
+    /// Exposing that matrix lets [`convert`] and [`Converter`] fuse it with the matrices of other color spaces,
+    /// so that converting between them multiplies by a single matrix.
+    ///
+    /// This must satisfy `to_xyz(c) == LINEAR_TO_XYZ × to_linear(c)` for every color `c`,
+    /// and [`XYZ_TO_LINEAR`](CoreColorSpace::XYZ_TO_LINEAR) and [`from_linear`](CoreColorSpace::from_linear)
+    /// must likewise agree with [`from_xyz`](CoreColorSpace::from_xyz),
+    /// so a color space that overrides one of these four items must override all of them.
+    /// The defaults make the linear stage XYZ itself, which is correct for any color space.
+    const LINEAR_TO_XYZ: [[f64; 3]; 3] = matrix::IDENTITY;
+
+    /// The inverse of [`LINEAR_TO_XYZ`](CoreColorSpace::LINEAR_TO_XYZ).
+    const XYZ_TO_LINEAR: [[f64; 3]; 3] = matrix::IDENTITY;
+
+    /// Convert the color of `Self` to its linear stage.
+    ///
+    /// The default converts the color to XYZ,
+    /// which is only correct if [`LINEAR_TO_XYZ`](CoreColorSpace::LINEAR_TO_XYZ) is left as the identity matrix.
+    fn to_linear(self) -> [Self::Component; 3]
+    where
+        Self: Sized,
+    {
+        let xyz = self.to_xyz();
+        [xyz.x, xyz.y, xyz.z]
+    }
+
+    /// Convert a color from its linear stage to the color space that `Self` represents.
+    ///
+    /// The default converts the color from XYZ,
+    /// which is only correct if [`XYZ_TO_LINEAR`](CoreColorSpace::XYZ_TO_LINEAR) is left as the identity matrix.
+    fn from_linear([x, y, z]: [Self::Component; 3]) -> Self
+    where
+        Self: Sized,
+    {
+        Self::from_xyz(Xyz::new(x, y, z))
+    }
 }
 
 /// A color space.
@@ -118,12 +237,45 @@ pub trait ColorSpace {
 }
 
 /// Convert a color from one color space to another.
-pub fn convert<In: CoreColorSpace, Out: CoreColorSpace>(color: In) -> Out {
-    let xyz = color.to_xyz();
-    Out::from_xyz(xyz)
+///
+/// If the color spaces have different white points, the color is chromatically adapted
+/// using the Bradford transform.
+///
+/// The matrices of both color spaces and the chromatic adaptation are combined at compile time,
+/// so only a single matrix is applied to the color;
+/// see [`CoreColorSpace::LINEAR_TO_XYZ`].
+/// To convert many colors, or to use a different chromatic adaptation transform, use a [`Converter`].
+///
+/// Colors wrapped in [`Alpha`] or [`PremultipliedAlpha`] are converted as well, keeping their alpha.
+pub fn convert<In, Out>(color: In) -> Out
+where
+    In: ConvertInto<Out>,
+{
+    color.convert_into()
+}
+
+/// A color that [`convert`] can convert to `Out`.
+///
+/// This is implemented between any two [`CoreColorSpace`]s with the same component type,
+/// and between [`Alpha`]s or [`PremultipliedAlpha`]s of them.
+pub trait ConvertInto<Out> {
+    /// Converts the color to `Out`.
+    fn convert_into(self) -> Out;
+}
+
+impl<In, Out> ConvertInto<Out> for In
+where
+    In: CoreColorSpace,
+    Out: CoreColorSpace<Component = In::Component>,
+{
+    fn convert_into(self) -> Out {
+        let linear = matrix::apply(converter::Plan::<In, Out>::MATRIX, self.to_linear());
+        Out::from_linear(linear)
+    }
 }
 
-fn approx_in_range(n: f32, range: std::ops::Range<f32>) -> bool {
-    let fudged_range = range.start - 0.005..range.end + 0.005;
+fn approx_in_range<T: Float>(n: T, range: std::ops::Range<T>) -> bool {
+    let fudge = T::from_f64(0.005);
+    let fudged_range = range.start - fudge..range.end + fudge;
     fudged_range.contains(&n)
 }
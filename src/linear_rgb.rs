@@ -1,76 +1,102 @@
 /// An RGB color without gamma correction.
 #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
-pub struct LinearRgb {
+#[repr(C)]
+pub struct LinearRgb<T = f32> {
     /// Red (0 to 1).
-    pub r: f32,
+    pub r: T,
     /// Green (0 to 1).
-    pub g: f32,
+    pub g: T,
     /// Blue (0 to 1).
-    pub b: f32,
+    pub b: T,
 }
 
-impl crate::ColorSpace for LinearRgb {
+pub(crate) const TO_XYZ: crate::matrix::Matrix = [
+    [0.4124564, 0.3575761, 0.1804375],
+    [0.2126729, 0.7151522, 0.0721750],
+    [0.0193339, 0.1191920, 0.9503041],
+];
+
+pub(crate) const FROM_XYZ: crate::matrix::Matrix = crate::matrix::inverse(TO_XYZ);
+
+impl<T: crate::Float> crate::ColorSpace for LinearRgb<T> {
     const BLACK: Self = Self {
-        r: 0.0,
-        g: 0.0,
-        b: 0.0,
+        r: T::ZERO,
+        g: T::ZERO,
+        b: T::ZERO,
     };
 
     const WHITE: Self = Self {
-        r: 1.0,
-        g: 1.0,
-        b: 1.0,
+        r: T::ONE,
+        g: T::ONE,
+        b: T::ONE,
     };
 
     fn in_bounds(self) -> bool {
-        crate::approx_in_range(self.r, 0.0..1.0)
-            && crate::approx_in_range(self.g, 0.0..1.0)
-            && crate::approx_in_range(self.b, 0.0..1.0)
+        crate::approx_in_range(self.r, T::ZERO..T::ONE)
+            && crate::approx_in_range(self.g, T::ZERO..T::ONE)
+            && crate::approx_in_range(self.b, T::ZERO..T::ONE)
     }
 }
 
-impl crate::CoreColorSpace for LinearRgb {
-    fn from_xyz(xyz: crate::Xyz) -> Self {
-        let r = xyz.x * 3.2404542 + xyz.y * -1.5371385 + xyz.z * -0.4985314;
-        let g = xyz.x * -0.9692660 + xyz.y * 1.8760108 + xyz.z * 0.0415560;
-        let b = xyz.x * 0.0556434 + xyz.y * -0.2040259 + xyz.z * 1.0572252;
+impl<T: crate::Float> crate::CoreColorSpace for LinearRgb<T> {
+    type WhitePoint = crate::illuminant::D65;
+    type Component = T;
+
+    const LINEAR_TO_XYZ: [[f64; 3]; 3] = TO_XYZ;
+    const XYZ_TO_LINEAR: [[f64; 3]; 3] = FROM_XYZ;
+
+    fn from_xyz(xyz: crate::Xyz<crate::illuminant::D65, T>) -> Self {
+        let [r, g, b] = crate::matrix::apply(FROM_XYZ, [xyz.x, xyz.y, xyz.z]);
 
         Self { r, g, b }
     }
 
-    fn to_xyz(self) -> crate::Xyz {
-        crate::Xyz {
-            x: self.r * 0.4124564 + self.g * 0.3575761 + self.b * 0.1804375,
-            y: self.r * 0.2126729 + self.g * 0.7151522 + self.b * 0.0721750,
-            z: self.r * 0.0193339 + self.g * 0.1191920 + self.b * 0.9503041,
-        }
+    fn to_xyz(self) -> crate::Xyz<crate::illuminant::D65, T> {
+        let [x, y, z] = crate::matrix::apply(TO_XYZ, [self.r, self.g, self.b]);
+
+        crate::Xyz::new(x, y, z)
+    }
+
+    fn to_linear(self) -> [T; 3] {
+        [self.r, self.g, self.b]
     }
-}
 
-impl From<crate::Srgb> for LinearRgb {
-    fn from(srgb: crate::Srgb) -> Self {
-        let to_linear = |n: f32| {
-            if n <= 0.04045 {
-                n / 12.92
-            } else {
-                ((n + 0.055) / 1.055).powf(2.4)
-            }
-        };
+    fn from_linear([r, g, b]: [T; 3]) -> Self {
+        Self { r, g, b }
+    }
+}
 
+impl<T: crate::Float> From<crate::Srgb<T>> for LinearRgb<T> {
+    fn from(srgb: crate::Srgb<T>) -> Self {
         Self {
-            r: to_linear(srgb.r),
-            g: to_linear(srgb.g),
-            b: to_linear(srgb.b),
+            r: crate::transfer::srgb_decode(srgb.r),
+            g: crate::transfer::srgb_decode(srgb.g),
+            b: crate::transfer::srgb_decode(srgb.b),
         }
     }
 }
 
-impl crate::Hex for LinearRgb {
-    fn components(self) -> (f32, f32, f32) {
+impl<T: crate::Float> crate::Hex<T> for LinearRgb<T> {
+    fn components(self) -> (T, T, T) {
         (self.r, self.g, self.b)
     }
 }
 
+impl<T: crate::Float> crate::FromHex<T> for LinearRgb<T> {
+    fn from_components((r, g, b): (T, T, T)) -> Self {
+        Self { r, g, b }
+    }
+}
+
+impl<T: crate::Float> std::str::FromStr for LinearRgb<T> {
+    type Err = crate::ParseHexError;
+
+    /// Parses a hex string as an sRGB color, as CSS does, and decodes it.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse::<crate::Srgb<T>>().map(Self::from)
+    }
+}
+
 #[cfg(test)]
 #[test]
 fn hex() {
@@ -84,3 +110,13 @@ fn hex() {
 
     assert_eq!(rgb.hex(), 0xff8000);
 }
+
+#[cfg(test)]
+#[test]
+fn parses_hex_as_srgb() {
+    let orange: LinearRgb<f64> = "#ff800080".parse().unwrap();
+    let srgb: crate::Srgb<f64> = crate::FromHex::from_hex(0xff8000);
+
+    assert_eq!(orange, LinearRgb::from(srgb));
+    assert!((orange.g - 0.21586).abs() < 1e-5);
+}
@@ -0,0 +1,116 @@
+use std::fmt;
+use std::marker::PhantomData;
+
+/// An RGB color without gamma correction in the color space defined by `S`.
+#[repr(C)]
+pub struct LinearRgbIn<S, T = f32> {
+    /// Red (0 to 1).
+    pub r: T,
+    /// Green (0 to 1).
+    pub g: T,
+    /// Blue (0 to 1).
+    pub b: T,
+    space: PhantomData<S>,
+}
+
+impl<S, T> LinearRgbIn<S, T> {
+    /// Creates a new color from its components.
+    pub const fn new(r: T, g: T, b: T) -> Self {
+        Self {
+            r,
+            g,
+            b,
+            space: PhantomData,
+        }
+    }
+}
+
+impl<S, T: fmt::Debug> fmt::Debug for LinearRgbIn<S, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LinearRgbIn")
+            .field("r", &self.r)
+            .field("g", &self.g)
+            .field("b", &self.b)
+            .finish()
+    }
+}
+
+impl<S, T: Copy> Clone for LinearRgbIn<S, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<S, T: Copy> Copy for LinearRgbIn<S, T> {}
+
+impl<S, T: PartialEq> PartialEq for LinearRgbIn<S, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.r == other.r && self.g == other.g && self.b == other.b
+    }
+}
+
+impl<S, T: PartialOrd> PartialOrd for LinearRgbIn<S, T> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        (&self.r, &self.g, &self.b).partial_cmp(&(&other.r, &other.g, &other.b))
+    }
+}
+
+impl<S, T: crate::Float> crate::ColorSpace for LinearRgbIn<S, T> {
+    const BLACK: Self = Self::new(T::ZERO, T::ZERO, T::ZERO);
+
+    const WHITE: Self = Self::new(T::ONE, T::ONE, T::ONE);
+
+    fn in_bounds(self) -> bool {
+        crate::approx_in_range(self.r, T::ZERO..T::ONE)
+            && crate::approx_in_range(self.g, T::ZERO..T::ONE)
+            && crate::approx_in_range(self.b, T::ZERO..T::ONE)
+    }
+}
+
+impl<S: crate::RgbSpaceDefinition, T: crate::Float> crate::CoreColorSpace for LinearRgbIn<S, T> {
+    type WhitePoint = S::WhitePoint;
+    type Component = T;
+
+    const LINEAR_TO_XYZ: [[f64; 3]; 3] = crate::rgb_space::SpaceOf::<S>::SPACE.to_xyz_matrix();
+    const XYZ_TO_LINEAR: [[f64; 3]; 3] = crate::rgb_space::SpaceOf::<S>::SPACE.from_xyz_matrix();
+
+    fn from_xyz(xyz: crate::Xyz<S::WhitePoint, T>) -> Self {
+        let [r, g, b] = crate::matrix::apply(
+            crate::rgb_space::SpaceOf::<S>::SPACE.from_xyz_matrix(),
+            [xyz.x, xyz.y, xyz.z],
+        );
+
+        Self::new(r, g, b)
+    }
+
+    fn to_xyz(self) -> crate::Xyz<S::WhitePoint, T> {
+        let [x, y, z] = crate::matrix::apply(
+            crate::rgb_space::SpaceOf::<S>::SPACE.to_xyz_matrix(),
+            [self.r, self.g, self.b],
+        );
+
+        crate::Xyz::new(x, y, z)
+    }
+
+    fn to_linear(self) -> [T; 3] {
+        [self.r, self.g, self.b]
+    }
+
+    fn from_linear([r, g, b]: [T; 3]) -> Self {
+        Self::new(r, g, b)
+    }
+}
+
+impl<S: crate::RgbSpaceDefinition, T: crate::Float> From<crate::EncodedRgbIn<S, T>>
+    for LinearRgbIn<S, T>
+{
+    fn from(encoded: crate::EncodedRgbIn<S, T>) -> Self {
+        let transfer = crate::rgb_space::SpaceOf::<S>::SPACE.transfer();
+
+        Self::new(
+            transfer.decode(encoded.r),
+            transfer.decode(encoded.g),
+            transfer.decode(encoded.b),
+        )
+    }
+}
@@ -0,0 +1,139 @@
+use crate::float::const_from_f64;
+use std::marker::PhantomData;
+
+/// A color from the [CIELUV] color space (also known as CIE L\*u\*v\*), relative to the white point `W`.
+///
+/// [CIELUV]: https://en.wikipedia.org/wiki/CIELUV
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct Luv<W = crate::illuminant::D65, T = f32> {
+    /// Lightness.
+    /// 0 is complete black, 100 is the brightest white.
+    pub l: T,
+    /// Green vs red.
+    /// Ranges from -134 (green) to 220 (red).
+    pub u: T,
+    /// Blue vs yellow.
+    /// Ranges from -140 (blue) to 122 (yellow).
+    pub v: T,
+    white_point: PhantomData<W>,
+}
+
+impl<W, T> Luv<W, T> {
+    /// Creates a new color from its components.
+    pub const fn new(l: T, u: T, v: T) -> Self {
+        Self {
+            l,
+            u,
+            v,
+            white_point: PhantomData,
+        }
+    }
+}
+
+const EPSILON: f64 = (6.0 / 29.0) * (6.0 / 29.0) * (6.0 / 29.0);
+
+const KAPPA: f64 = (29.0 / 3.0) * (29.0 / 3.0) * (29.0 / 3.0);
+
+/// Computes the CIE 1976 u′v′ chromaticity coordinates of a color.
+fn uv_prime<T: crate::Float>([x, y, z]: [T; 3]) -> (T, T) {
+    let denominator = x + T::from_f64(15.0) * y + T::from_f64(3.0) * z;
+
+    if denominator == T::ZERO {
+        return (T::ZERO, T::ZERO);
+    }
+
+    (
+        T::from_f64(4.0) * x / denominator,
+        T::from_f64(9.0) * y / denominator,
+    )
+}
+
+impl<W, T: crate::Float> From<crate::Lchuv<W, T>> for Luv<W, T> {
+    fn from(lchuv: crate::Lchuv<W, T>) -> Self {
+        Self::new(
+            lchuv.l,
+            lchuv.c * lchuv.h.unnormalized_radians.cos(),
+            lchuv.c * lchuv.h.unnormalized_radians.sin(),
+        )
+    }
+}
+
+impl<W, T: crate::Float> crate::ColorSpace for Luv<W, T> {
+    const BLACK: Self = Self::new(T::ZERO, T::ZERO, T::ZERO);
+
+    const WHITE: Self = Self::new(const_from_f64(100.0), T::ZERO, T::ZERO);
+
+    fn in_bounds(self) -> bool {
+        crate::approx_in_range(self.l, T::ZERO..T::from_f64(100.0))
+            && crate::approx_in_range(self.u, T::from_f64(-134.0)..T::from_f64(220.0))
+            && crate::approx_in_range(self.v, T::from_f64(-140.0)..T::from_f64(122.0))
+    }
+}
+
+impl<W: crate::WhitePoint, T: crate::Float> crate::CoreColorSpace for Luv<W, T> {
+    type WhitePoint = W;
+    type Component = T;
+
+    fn from_xyz(xyz: crate::Xyz<W, T>) -> Self {
+        let white = W::XYZ.map(T::from_f64);
+        let (u_prime, v_prime) = uv_prime([xyz.x, xyz.y, xyz.z]);
+        let (u_prime_white, v_prime_white) = uv_prime(white);
+
+        let y = xyz.y / white[1];
+        let l = if y > T::from_f64(EPSILON) {
+            T::from_f64(116.0) * y.cbrt() - T::from_f64(16.0)
+        } else {
+            T::from_f64(KAPPA) * y
+        };
+
+        Self::new(
+            l,
+            T::from_f64(13.0) * l * (u_prime - u_prime_white),
+            T::from_f64(13.0) * l * (v_prime - v_prime_white),
+        )
+    }
+
+    fn to_xyz(self) -> crate::Xyz<W, T> {
+        if self.l <= T::ZERO {
+            return crate::Xyz::new(T::ZERO, T::ZERO, T::ZERO);
+        }
+
+        let white = W::XYZ.map(T::from_f64);
+        let (u_prime_white, v_prime_white) = uv_prime(white);
+
+        let u_prime = self.u / (T::from_f64(13.0) * self.l) + u_prime_white;
+        let v_prime = self.v / (T::from_f64(13.0) * self.l) + v_prime_white;
+
+        let y = if self.l > T::from_f64(KAPPA * EPSILON) {
+            white[1] * ((self.l + T::from_f64(16.0)) / T::from_f64(116.0)).powi(3)
+        } else {
+            white[1] * self.l / T::from_f64(KAPPA)
+        };
+
+        let four_v_prime = T::from_f64(4.0) * v_prime;
+
+        crate::Xyz::new(
+            y * T::from_f64(9.0) * u_prime / four_v_prime,
+            y,
+            y * (T::from_f64(12.0) - T::from_f64(3.0) * u_prime - T::from_f64(20.0) * v_prime)
+                / four_v_prime,
+        )
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn red() {
+    use crate::LinearRgb;
+
+    let luv: Luv = crate::convert(LinearRgb {
+        r: 1.0,
+        g: 0.0,
+        b: 0.0,
+    });
+
+    assert!((luv.l - 53.2408).abs() < 0.001);
+    assert!((luv.u - 175.0151).abs() < 0.01);
+    assert!((luv.v - 37.7564).abs() < 0.01);
+}
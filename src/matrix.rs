@@ -0,0 +1,69 @@
+//! 3×3 matrix helpers.
+//!
+//! Matrices are stored and derived at full precision, and only converted to the component type of a color when applied to it.
+
+pub(crate) type Matrix = [[f64; 3]; 3];
+
+pub(crate) const IDENTITY: Matrix = diagonal([1.0, 1.0, 1.0]);
+
+pub(crate) const fn mul(a: Matrix, b: Matrix) -> Matrix {
+    let mut result = [[0.0; 3]; 3];
+
+    let mut i = 0;
+    while i < 3 {
+        let mut j = 0;
+        while j < 3 {
+            result[i][j] = a[i][0] * b[0][j] + a[i][1] * b[1][j] + a[i][2] * b[2][j];
+            j += 1;
+        }
+        i += 1;
+    }
+
+    result
+}
+
+pub(crate) const fn mul_vector(m: Matrix, v: [f64; 3]) -> [f64; 3] {
+    [
+        m[0][0] * v[0] + m[0][1] * v[1] + m[0][2] * v[2],
+        m[1][0] * v[0] + m[1][1] * v[1] + m[1][2] * v[2],
+        m[2][0] * v[0] + m[2][1] * v[1] + m[2][2] * v[2],
+    ]
+}
+
+pub(crate) const fn diagonal(v: [f64; 3]) -> Matrix {
+    [[v[0], 0.0, 0.0], [0.0, v[1], 0.0], [0.0, 0.0, v[2]]]
+}
+
+pub(crate) const fn inverse(m: Matrix) -> Matrix {
+    let [[a, b, c], [d, e, f], [g, h, i]] = m;
+
+    let cofactor_a = e * i - f * h;
+    let cofactor_b = f * g - d * i;
+    let cofactor_c = d * h - e * g;
+    let determinant = a * cofactor_a + b * cofactor_b + c * cofactor_c;
+
+    [
+        [
+            cofactor_a / determinant,
+            (c * h - b * i) / determinant,
+            (b * f - c * e) / determinant,
+        ],
+        [
+            cofactor_b / determinant,
+            (a * i - c * g) / determinant,
+            (c * d - a * f) / determinant,
+        ],
+        [
+            cofactor_c / determinant,
+            (b * g - a * h) / determinant,
+            (a * e - b * d) / determinant,
+        ],
+    ]
+}
+
+pub(crate) fn apply<T: crate::Float>(m: Matrix, [a, b, c]: [T; 3]) -> [T; 3] {
+    let row =
+        |row: [f64; 3]| a * T::from_f64(row[0]) + b * T::from_f64(row[1]) + c * T::from_f64(row[2]);
+
+    [row(m[0]), row(m[1]), row(m[2])]
+}
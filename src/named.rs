@@ -0,0 +1,715 @@
+//! The 148 [named colors of CSS][named-colors].
+//!
+//! Each color is a constant, like [`REBECCAPURPLE`], and can be looked up by name with [`lookup`].
+//! [`nearest`] names an arbitrary color after the named color it looks most like.
+//!
+//! ```
+//! use tincture::named;
+//! use tincture::Srgb;
+//!
+//! assert_eq!(named::lookup("RebeccaPurple"), Some(named::REBECCAPURPLE));
+//!
+//! let almost_purple: Srgb = Srgb { r: 0.42, g: 0.2, b: 0.58 };
+//! assert_eq!(named::nearest(almost_purple).0, "rebeccapurple");
+//! ```
+//!
+//! [named-colors]: https://www.w3.org/TR/css-color-4/#named-colors
+
+use crate::{Float, FromHex, Hex, Srgb};
+
+/// Creates a color from a hex value in a constant.
+const fn hex(hex: u32) -> Srgb {
+    Srgb {
+        r: ((hex >> 16) & 0xff) as f32 / 255.0,
+        g: ((hex >> 8) & 0xff) as f32 / 255.0,
+        b: (hex & 0xff) as f32 / 255.0,
+    }
+}
+
+/// `aliceblue` (`#f0f8ff`).
+pub const ALICEBLUE: Srgb = hex(0xf0f8ff);
+
+/// `antiquewhite` (`#faebd7`).
+pub const ANTIQUEWHITE: Srgb = hex(0xfaebd7);
+
+/// `aqua` (`#00ffff`).
+pub const AQUA: Srgb = hex(0x00ffff);
+
+/// `aquamarine` (`#7fffd4`).
+pub const AQUAMARINE: Srgb = hex(0x7fffd4);
+
+/// `azure` (`#f0ffff`).
+pub const AZURE: Srgb = hex(0xf0ffff);
+
+/// `beige` (`#f5f5dc`).
+pub const BEIGE: Srgb = hex(0xf5f5dc);
+
+/// `bisque` (`#ffe4c4`).
+pub const BISQUE: Srgb = hex(0xffe4c4);
+
+/// `black` (`#000000`).
+pub const BLACK: Srgb = hex(0x000000);
+
+/// `blanchedalmond` (`#ffebcd`).
+pub const BLANCHEDALMOND: Srgb = hex(0xffebcd);
+
+/// `blue` (`#0000ff`).
+pub const BLUE: Srgb = hex(0x0000ff);
+
+/// `blueviolet` (`#8a2be2`).
+pub const BLUEVIOLET: Srgb = hex(0x8a2be2);
+
+/// `brown` (`#a52a2a`).
+pub const BROWN: Srgb = hex(0xa52a2a);
+
+/// `burlywood` (`#deb887`).
+pub const BURLYWOOD: Srgb = hex(0xdeb887);
+
+/// `cadetblue` (`#5f9ea0`).
+pub const CADETBLUE: Srgb = hex(0x5f9ea0);
+
+/// `chartreuse` (`#7fff00`).
+pub const CHARTREUSE: Srgb = hex(0x7fff00);
+
+/// `chocolate` (`#d2691e`).
+pub const CHOCOLATE: Srgb = hex(0xd2691e);
+
+/// `coral` (`#ff7f50`).
+pub const CORAL: Srgb = hex(0xff7f50);
+
+/// `cornflowerblue` (`#6495ed`).
+pub const CORNFLOWERBLUE: Srgb = hex(0x6495ed);
+
+/// `cornsilk` (`#fff8dc`).
+pub const CORNSILK: Srgb = hex(0xfff8dc);
+
+/// `crimson` (`#dc143c`).
+pub const CRIMSON: Srgb = hex(0xdc143c);
+
+/// `cyan` (`#00ffff`).
+pub const CYAN: Srgb = hex(0x00ffff);
+
+/// `darkblue` (`#00008b`).
+pub const DARKBLUE: Srgb = hex(0x00008b);
+
+/// `darkcyan` (`#008b8b`).
+pub const DARKCYAN: Srgb = hex(0x008b8b);
+
+/// `darkgoldenrod` (`#b8860b`).
+pub const DARKGOLDENROD: Srgb = hex(0xb8860b);
+
+/// `darkgray` (`#a9a9a9`).
+pub const DARKGRAY: Srgb = hex(0xa9a9a9);
+
+/// `darkgreen` (`#006400`).
+pub const DARKGREEN: Srgb = hex(0x006400);
+
+/// `darkgrey` (`#a9a9a9`).
+pub const DARKGREY: Srgb = hex(0xa9a9a9);
+
+/// `darkkhaki` (`#bdb76b`).
+pub const DARKKHAKI: Srgb = hex(0xbdb76b);
+
+/// `darkmagenta` (`#8b008b`).
+pub const DARKMAGENTA: Srgb = hex(0x8b008b);
+
+/// `darkolivegreen` (`#556b2f`).
+pub const DARKOLIVEGREEN: Srgb = hex(0x556b2f);
+
+/// `darkorange` (`#ff8c00`).
+pub const DARKORANGE: Srgb = hex(0xff8c00);
+
+/// `darkorchid` (`#9932cc`).
+pub const DARKORCHID: Srgb = hex(0x9932cc);
+
+/// `darkred` (`#8b0000`).
+pub const DARKRED: Srgb = hex(0x8b0000);
+
+/// `darksalmon` (`#e9967a`).
+pub const DARKSALMON: Srgb = hex(0xe9967a);
+
+/// `darkseagreen` (`#8fbc8f`).
+pub const DARKSEAGREEN: Srgb = hex(0x8fbc8f);
+
+/// `darkslateblue` (`#483d8b`).
+pub const DARKSLATEBLUE: Srgb = hex(0x483d8b);
+
+/// `darkslategray` (`#2f4f4f`).
+pub const DARKSLATEGRAY: Srgb = hex(0x2f4f4f);
+
+/// `darkslategrey` (`#2f4f4f`).
+pub const DARKSLATEGREY: Srgb = hex(0x2f4f4f);
+
+/// `darkturquoise` (`#00ced1`).
+pub const DARKTURQUOISE: Srgb = hex(0x00ced1);
+
+/// `darkviolet` (`#9400d3`).
+pub const DARKVIOLET: Srgb = hex(0x9400d3);
+
+/// `deeppink` (`#ff1493`).
+pub const DEEPPINK: Srgb = hex(0xff1493);
+
+/// `deepskyblue` (`#00bfff`).
+pub const DEEPSKYBLUE: Srgb = hex(0x00bfff);
+
+/// `dimgray` (`#696969`).
+pub const DIMGRAY: Srgb = hex(0x696969);
+
+/// `dimgrey` (`#696969`).
+pub const DIMGREY: Srgb = hex(0x696969);
+
+/// `dodgerblue` (`#1e90ff`).
+pub const DODGERBLUE: Srgb = hex(0x1e90ff);
+
+/// `firebrick` (`#b22222`).
+pub const FIREBRICK: Srgb = hex(0xb22222);
+
+/// `floralwhite` (`#fffaf0`).
+pub const FLORALWHITE: Srgb = hex(0xfffaf0);
+
+/// `forestgreen` (`#228b22`).
+pub const FORESTGREEN: Srgb = hex(0x228b22);
+
+/// `fuchsia` (`#ff00ff`).
+pub const FUCHSIA: Srgb = hex(0xff00ff);
+
+/// `gainsboro` (`#dcdcdc`).
+pub const GAINSBORO: Srgb = hex(0xdcdcdc);
+
+/// `ghostwhite` (`#f8f8ff`).
+pub const GHOSTWHITE: Srgb = hex(0xf8f8ff);
+
+/// `gold` (`#ffd700`).
+pub const GOLD: Srgb = hex(0xffd700);
+
+/// `goldenrod` (`#daa520`).
+pub const GOLDENROD: Srgb = hex(0xdaa520);
+
+/// `gray` (`#808080`).
+pub const GRAY: Srgb = hex(0x808080);
+
+/// `green` (`#008000`).
+pub const GREEN: Srgb = hex(0x008000);
+
+/// `greenyellow` (`#adff2f`).
+pub const GREENYELLOW: Srgb = hex(0xadff2f);
+
+/// `grey` (`#808080`).
+pub const GREY: Srgb = hex(0x808080);
+
+/// `honeydew` (`#f0fff0`).
+pub const HONEYDEW: Srgb = hex(0xf0fff0);
+
+/// `hotpink` (`#ff69b4`).
+pub const HOTPINK: Srgb = hex(0xff69b4);
+
+/// `indianred` (`#cd5c5c`).
+pub const INDIANRED: Srgb = hex(0xcd5c5c);
+
+/// `indigo` (`#4b0082`).
+pub const INDIGO: Srgb = hex(0x4b0082);
+
+/// `ivory` (`#fffff0`).
+pub const IVORY: Srgb = hex(0xfffff0);
+
+/// `khaki` (`#f0e68c`).
+pub const KHAKI: Srgb = hex(0xf0e68c);
+
+/// `lavender` (`#e6e6fa`).
+pub const LAVENDER: Srgb = hex(0xe6e6fa);
+
+/// `lavenderblush` (`#fff0f5`).
+pub const LAVENDERBLUSH: Srgb = hex(0xfff0f5);
+
+/// `lawngreen` (`#7cfc00`).
+pub const LAWNGREEN: Srgb = hex(0x7cfc00);
+
+/// `lemonchiffon` (`#fffacd`).
+pub const LEMONCHIFFON: Srgb = hex(0xfffacd);
+
+/// `lightblue` (`#add8e6`).
+pub const LIGHTBLUE: Srgb = hex(0xadd8e6);
+
+/// `lightcoral` (`#f08080`).
+pub const LIGHTCORAL: Srgb = hex(0xf08080);
+
+/// `lightcyan` (`#e0ffff`).
+pub const LIGHTCYAN: Srgb = hex(0xe0ffff);
+
+/// `lightgoldenrodyellow` (`#fafad2`).
+pub const LIGHTGOLDENRODYELLOW: Srgb = hex(0xfafad2);
+
+/// `lightgray` (`#d3d3d3`).
+pub const LIGHTGRAY: Srgb = hex(0xd3d3d3);
+
+/// `lightgreen` (`#90ee90`).
+pub const LIGHTGREEN: Srgb = hex(0x90ee90);
+
+/// `lightgrey` (`#d3d3d3`).
+pub const LIGHTGREY: Srgb = hex(0xd3d3d3);
+
+/// `lightpink` (`#ffb6c1`).
+pub const LIGHTPINK: Srgb = hex(0xffb6c1);
+
+/// `lightsalmon` (`#ffa07a`).
+pub const LIGHTSALMON: Srgb = hex(0xffa07a);
+
+/// `lightseagreen` (`#20b2aa`).
+pub const LIGHTSEAGREEN: Srgb = hex(0x20b2aa);
+
+/// `lightskyblue` (`#87cefa`).
+pub const LIGHTSKYBLUE: Srgb = hex(0x87cefa);
+
+/// `lightslategray` (`#778899`).
+pub const LIGHTSLATEGRAY: Srgb = hex(0x778899);
+
+/// `lightslategrey` (`#778899`).
+pub const LIGHTSLATEGREY: Srgb = hex(0x778899);
+
+/// `lightsteelblue` (`#b0c4de`).
+pub const LIGHTSTEELBLUE: Srgb = hex(0xb0c4de);
+
+/// `lightyellow` (`#ffffe0`).
+pub const LIGHTYELLOW: Srgb = hex(0xffffe0);
+
+/// `lime` (`#00ff00`).
+pub const LIME: Srgb = hex(0x00ff00);
+
+/// `limegreen` (`#32cd32`).
+pub const LIMEGREEN: Srgb = hex(0x32cd32);
+
+/// `linen` (`#faf0e6`).
+pub const LINEN: Srgb = hex(0xfaf0e6);
+
+/// `magenta` (`#ff00ff`).
+pub const MAGENTA: Srgb = hex(0xff00ff);
+
+/// `maroon` (`#800000`).
+pub const MAROON: Srgb = hex(0x800000);
+
+/// `mediumaquamarine` (`#66cdaa`).
+pub const MEDIUMAQUAMARINE: Srgb = hex(0x66cdaa);
+
+/// `mediumblue` (`#0000cd`).
+pub const MEDIUMBLUE: Srgb = hex(0x0000cd);
+
+/// `mediumorchid` (`#ba55d3`).
+pub const MEDIUMORCHID: Srgb = hex(0xba55d3);
+
+/// `mediumpurple` (`#9370db`).
+pub const MEDIUMPURPLE: Srgb = hex(0x9370db);
+
+/// `mediumseagreen` (`#3cb371`).
+pub const MEDIUMSEAGREEN: Srgb = hex(0x3cb371);
+
+/// `mediumslateblue` (`#7b68ee`).
+pub const MEDIUMSLATEBLUE: Srgb = hex(0x7b68ee);
+
+/// `mediumspringgreen` (`#00fa9a`).
+pub const MEDIUMSPRINGGREEN: Srgb = hex(0x00fa9a);
+
+/// `mediumturquoise` (`#48d1cc`).
+pub const MEDIUMTURQUOISE: Srgb = hex(0x48d1cc);
+
+/// `mediumvioletred` (`#c71585`).
+pub const MEDIUMVIOLETRED: Srgb = hex(0xc71585);
+
+/// `midnightblue` (`#191970`).
+pub const MIDNIGHTBLUE: Srgb = hex(0x191970);
+
+/// `mintcream` (`#f5fffa`).
+pub const MINTCREAM: Srgb = hex(0xf5fffa);
+
+/// `mistyrose` (`#ffe4e1`).
+pub const MISTYROSE: Srgb = hex(0xffe4e1);
+
+/// `moccasin` (`#ffe4b5`).
+pub const MOCCASIN: Srgb = hex(0xffe4b5);
+
+/// `navajowhite` (`#ffdead`).
+pub const NAVAJOWHITE: Srgb = hex(0xffdead);
+
+/// `navy` (`#000080`).
+pub const NAVY: Srgb = hex(0x000080);
+
+/// `oldlace` (`#fdf5e6`).
+pub const OLDLACE: Srgb = hex(0xfdf5e6);
+
+/// `olive` (`#808000`).
+pub const OLIVE: Srgb = hex(0x808000);
+
+/// `olivedrab` (`#6b8e23`).
+pub const OLIVEDRAB: Srgb = hex(0x6b8e23);
+
+/// `orange` (`#ffa500`).
+pub const ORANGE: Srgb = hex(0xffa500);
+
+/// `orangered` (`#ff4500`).
+pub const ORANGERED: Srgb = hex(0xff4500);
+
+/// `orchid` (`#da70d6`).
+pub const ORCHID: Srgb = hex(0xda70d6);
+
+/// `palegoldenrod` (`#eee8aa`).
+pub const PALEGOLDENROD: Srgb = hex(0xeee8aa);
+
+/// `palegreen` (`#98fb98`).
+pub const PALEGREEN: Srgb = hex(0x98fb98);
+
+/// `paleturquoise` (`#afeeee`).
+pub const PALETURQUOISE: Srgb = hex(0xafeeee);
+
+/// `palevioletred` (`#db7093`).
+pub const PALEVIOLETRED: Srgb = hex(0xdb7093);
+
+/// `papayawhip` (`#ffefd5`).
+pub const PAPAYAWHIP: Srgb = hex(0xffefd5);
+
+/// `peachpuff` (`#ffdab9`).
+pub const PEACHPUFF: Srgb = hex(0xffdab9);
+
+/// `peru` (`#cd853f`).
+pub const PERU: Srgb = hex(0xcd853f);
+
+/// `pink` (`#ffc0cb`).
+pub const PINK: Srgb = hex(0xffc0cb);
+
+/// `plum` (`#dda0dd`).
+pub const PLUM: Srgb = hex(0xdda0dd);
+
+/// `powderblue` (`#b0e0e6`).
+pub const POWDERBLUE: Srgb = hex(0xb0e0e6);
+
+/// `purple` (`#800080`).
+pub const PURPLE: Srgb = hex(0x800080);
+
+/// `rebeccapurple` (`#663399`).
+pub const REBECCAPURPLE: Srgb = hex(0x663399);
+
+/// `red` (`#ff0000`).
+pub const RED: Srgb = hex(0xff0000);
+
+/// `rosybrown` (`#bc8f8f`).
+pub const ROSYBROWN: Srgb = hex(0xbc8f8f);
+
+/// `royalblue` (`#4169e1`).
+pub const ROYALBLUE: Srgb = hex(0x4169e1);
+
+/// `saddlebrown` (`#8b4513`).
+pub const SADDLEBROWN: Srgb = hex(0x8b4513);
+
+/// `salmon` (`#fa8072`).
+pub const SALMON: Srgb = hex(0xfa8072);
+
+/// `sandybrown` (`#f4a460`).
+pub const SANDYBROWN: Srgb = hex(0xf4a460);
+
+/// `seagreen` (`#2e8b57`).
+pub const SEAGREEN: Srgb = hex(0x2e8b57);
+
+/// `seashell` (`#fff5ee`).
+pub const SEASHELL: Srgb = hex(0xfff5ee);
+
+/// `sienna` (`#a0522d`).
+pub const SIENNA: Srgb = hex(0xa0522d);
+
+/// `silver` (`#c0c0c0`).
+pub const SILVER: Srgb = hex(0xc0c0c0);
+
+/// `skyblue` (`#87ceeb`).
+pub const SKYBLUE: Srgb = hex(0x87ceeb);
+
+/// `slateblue` (`#6a5acd`).
+pub const SLATEBLUE: Srgb = hex(0x6a5acd);
+
+/// `slategray` (`#708090`).
+pub const SLATEGRAY: Srgb = hex(0x708090);
+
+/// `slategrey` (`#708090`).
+pub const SLATEGREY: Srgb = hex(0x708090);
+
+/// `snow` (`#fffafa`).
+pub const SNOW: Srgb = hex(0xfffafa);
+
+/// `springgreen` (`#00ff7f`).
+pub const SPRINGGREEN: Srgb = hex(0x00ff7f);
+
+/// `steelblue` (`#4682b4`).
+pub const STEELBLUE: Srgb = hex(0x4682b4);
+
+/// `tan` (`#d2b48c`).
+pub const TAN: Srgb = hex(0xd2b48c);
+
+/// `teal` (`#008080`).
+pub const TEAL: Srgb = hex(0x008080);
+
+/// `thistle` (`#d8bfd8`).
+pub const THISTLE: Srgb = hex(0xd8bfd8);
+
+/// `tomato` (`#ff6347`).
+pub const TOMATO: Srgb = hex(0xff6347);
+
+/// `turquoise` (`#40e0d0`).
+pub const TURQUOISE: Srgb = hex(0x40e0d0);
+
+/// `violet` (`#ee82ee`).
+pub const VIOLET: Srgb = hex(0xee82ee);
+
+/// `wheat` (`#f5deb3`).
+pub const WHEAT: Srgb = hex(0xf5deb3);
+
+/// `white` (`#ffffff`).
+pub const WHITE: Srgb = hex(0xffffff);
+
+/// `whitesmoke` (`#f5f5f5`).
+pub const WHITESMOKE: Srgb = hex(0xf5f5f5);
+
+/// `yellow` (`#ffff00`).
+pub const YELLOW: Srgb = hex(0xffff00);
+
+/// `yellowgreen` (`#9acd32`).
+pub const YELLOWGREEN: Srgb = hex(0x9acd32);
+
+/// Every named color and its name, sorted by name.
+///
+/// Some colors have two names, like `aqua` and `cyan`, or `gray` and `grey`.
+pub const COLORS: [(&str, Srgb); 148] = [
+    ("aliceblue", ALICEBLUE),
+    ("antiquewhite", ANTIQUEWHITE),
+    ("aqua", AQUA),
+    ("aquamarine", AQUAMARINE),
+    ("azure", AZURE),
+    ("beige", BEIGE),
+    ("bisque", BISQUE),
+    ("black", BLACK),
+    ("blanchedalmond", BLANCHEDALMOND),
+    ("blue", BLUE),
+    ("blueviolet", BLUEVIOLET),
+    ("brown", BROWN),
+    ("burlywood", BURLYWOOD),
+    ("cadetblue", CADETBLUE),
+    ("chartreuse", CHARTREUSE),
+    ("chocolate", CHOCOLATE),
+    ("coral", CORAL),
+    ("cornflowerblue", CORNFLOWERBLUE),
+    ("cornsilk", CORNSILK),
+    ("crimson", CRIMSON),
+    ("cyan", CYAN),
+    ("darkblue", DARKBLUE),
+    ("darkcyan", DARKCYAN),
+    ("darkgoldenrod", DARKGOLDENROD),
+    ("darkgray", DARKGRAY),
+    ("darkgreen", DARKGREEN),
+    ("darkgrey", DARKGREY),
+    ("darkkhaki", DARKKHAKI),
+    ("darkmagenta", DARKMAGENTA),
+    ("darkolivegreen", DARKOLIVEGREEN),
+    ("darkorange", DARKORANGE),
+    ("darkorchid", DARKORCHID),
+    ("darkred", DARKRED),
+    ("darksalmon", DARKSALMON),
+    ("darkseagreen", DARKSEAGREEN),
+    ("darkslateblue", DARKSLATEBLUE),
+    ("darkslategray", DARKSLATEGRAY),
+    ("darkslategrey", DARKSLATEGREY),
+    ("darkturquoise", DARKTURQUOISE),
+    ("darkviolet", DARKVIOLET),
+    ("deeppink", DEEPPINK),
+    ("deepskyblue", DEEPSKYBLUE),
+    ("dimgray", DIMGRAY),
+    ("dimgrey", DIMGREY),
+    ("dodgerblue", DODGERBLUE),
+    ("firebrick", FIREBRICK),
+    ("floralwhite", FLORALWHITE),
+    ("forestgreen", FORESTGREEN),
+    ("fuchsia", FUCHSIA),
+    ("gainsboro", GAINSBORO),
+    ("ghostwhite", GHOSTWHITE),
+    ("gold", GOLD),
+    ("goldenrod", GOLDENROD),
+    ("gray", GRAY),
+    ("green", GREEN),
+    ("greenyellow", GREENYELLOW),
+    ("grey", GREY),
+    ("honeydew", HONEYDEW),
+    ("hotpink", HOTPINK),
+    ("indianred", INDIANRED),
+    ("indigo", INDIGO),
+    ("ivory", IVORY),
+    ("khaki", KHAKI),
+    ("lavender", LAVENDER),
+    ("lavenderblush", LAVENDERBLUSH),
+    ("lawngreen", LAWNGREEN),
+    ("lemonchiffon", LEMONCHIFFON),
+    ("lightblue", LIGHTBLUE),
+    ("lightcoral", LIGHTCORAL),
+    ("lightcyan", LIGHTCYAN),
+    ("lightgoldenrodyellow", LIGHTGOLDENRODYELLOW),
+    ("lightgray", LIGHTGRAY),
+    ("lightgreen", LIGHTGREEN),
+    ("lightgrey", LIGHTGREY),
+    ("lightpink", LIGHTPINK),
+    ("lightsalmon", LIGHTSALMON),
+    ("lightseagreen", LIGHTSEAGREEN),
+    ("lightskyblue", LIGHTSKYBLUE),
+    ("lightslategray", LIGHTSLATEGRAY),
+    ("lightslategrey", LIGHTSLATEGREY),
+    ("lightsteelblue", LIGHTSTEELBLUE),
+    ("lightyellow", LIGHTYELLOW),
+    ("lime", LIME),
+    ("limegreen", LIMEGREEN),
+    ("linen", LINEN),
+    ("magenta", MAGENTA),
+    ("maroon", MAROON),
+    ("mediumaquamarine", MEDIUMAQUAMARINE),
+    ("mediumblue", MEDIUMBLUE),
+    ("mediumorchid", MEDIUMORCHID),
+    ("mediumpurple", MEDIUMPURPLE),
+    ("mediumseagreen", MEDIUMSEAGREEN),
+    ("mediumslateblue", MEDIUMSLATEBLUE),
+    ("mediumspringgreen", MEDIUMSPRINGGREEN),
+    ("mediumturquoise", MEDIUMTURQUOISE),
+    ("mediumvioletred", MEDIUMVIOLETRED),
+    ("midnightblue", MIDNIGHTBLUE),
+    ("mintcream", MINTCREAM),
+    ("mistyrose", MISTYROSE),
+    ("moccasin", MOCCASIN),
+    ("navajowhite", NAVAJOWHITE),
+    ("navy", NAVY),
+    ("oldlace", OLDLACE),
+    ("olive", OLIVE),
+    ("olivedrab", OLIVEDRAB),
+    ("orange", ORANGE),
+    ("orangered", ORANGERED),
+    ("orchid", ORCHID),
+    ("palegoldenrod", PALEGOLDENROD),
+    ("palegreen", PALEGREEN),
+    ("paleturquoise", PALETURQUOISE),
+    ("palevioletred", PALEVIOLETRED),
+    ("papayawhip", PAPAYAWHIP),
+    ("peachpuff", PEACHPUFF),
+    ("peru", PERU),
+    ("pink", PINK),
+    ("plum", PLUM),
+    ("powderblue", POWDERBLUE),
+    ("purple", PURPLE),
+    ("rebeccapurple", REBECCAPURPLE),
+    ("red", RED),
+    ("rosybrown", ROSYBROWN),
+    ("royalblue", ROYALBLUE),
+    ("saddlebrown", SADDLEBROWN),
+    ("salmon", SALMON),
+    ("sandybrown", SANDYBROWN),
+    ("seagreen", SEAGREEN),
+    ("seashell", SEASHELL),
+    ("sienna", SIENNA),
+    ("silver", SILVER),
+    ("skyblue", SKYBLUE),
+    ("slateblue", SLATEBLUE),
+    ("slategray", SLATEGRAY),
+    ("slategrey", SLATEGREY),
+    ("snow", SNOW),
+    ("springgreen", SPRINGGREEN),
+    ("steelblue", STEELBLUE),
+    ("tan", TAN),
+    ("teal", TEAL),
+    ("thistle", THISTLE),
+    ("tomato", TOMATO),
+    ("turquoise", TURQUOISE),
+    ("violet", VIOLET),
+    ("wheat", WHEAT),
+    ("white", WHITE),
+    ("whitesmoke", WHITESMOKE),
+    ("yellow", YELLOW),
+    ("yellowgreen", YELLOWGREEN),
+];
+
+/// The named color `name`, ignoring case.
+///
+/// ```
+/// use tincture::named;
+///
+/// assert_eq!(named::lookup("AliceBlue"), Some(named::ALICEBLUE));
+/// assert_eq!(named::lookup("alice blue"), None);
+/// ```
+pub fn lookup(name: &str) -> Option<Srgb> {
+    let name = name.to_ascii_lowercase();
+
+    COLORS
+        .binary_search_by(|&(candidate, _)| candidate.cmp(name.as_str()))
+        .ok()
+        .map(|index| COLORS[index].1)
+}
+
+/// The named color closest to `color`, and its name.
+///
+/// Distance is measured in [`Oklab`](crate::Oklab), so the closest color is the one that looks most similar.
+/// Of two names for the same color, the first in alphabetical order is chosen.
+pub fn nearest<T: Float>(color: Srgb<T>) -> (&'static str, Srgb) {
+    let oklab = to_oklab(color);
+    let distance = |named: Srgb| {
+        // Going through hex makes `named` exact in `T`.
+        let named = to_oklab(Srgb::<T>::from_hex(named.hex()));
+        let (l, a, b) = (oklab.l - named.l, oklab.a - named.a, oklab.b - named.b);
+        l * l + a * a + b * b
+    };
+
+    let mut nearest = COLORS[0];
+    let mut nearest_distance = distance(nearest.1);
+
+    for &(name, named) in &COLORS[1..] {
+        let distance = distance(named);
+
+        if distance < nearest_distance {
+            nearest = (name, named);
+            nearest_distance = distance;
+        }
+    }
+
+    nearest
+}
+
+fn to_oklab<T: Float>(color: Srgb<T>) -> crate::Oklab<T> {
+    crate::convert(crate::LinearRgb::from(color))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn constants_match_their_names() {
+        assert_eq!(REBECCAPURPLE.hex(), 0x663399);
+        assert_eq!(lookup("REBECCAPURPLE"), Some(REBECCAPURPLE));
+        assert_eq!(lookup("transparent"), None);
+
+        for window in COLORS.windows(2) {
+            assert!(window[0].0 < window[1].0);
+        }
+    }
+
+    #[test]
+    fn nearest_prefers_the_closest_color() {
+        for &(name, color) in &COLORS {
+            let (nearest_name, nearest_color) = nearest(color);
+            assert_eq!(nearest_color, color);
+            assert!(nearest_name <= name);
+        }
+
+        let nearly_red: Srgb<f64> = Srgb {
+            r: 0.95,
+            g: 0.05,
+            b: 0.02,
+        };
+        assert_eq!(nearest(nearly_red).0, "red");
+        assert_eq!(
+            nearest(Srgb {
+                r: 0.0,
+                g: 1.0,
+                b: 1.0
+            })
+            .0,
+            "aqua"
+        );
+    }
+}
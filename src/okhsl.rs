@@ -0,0 +1,192 @@
+/// A color from [Okhsl], an HSL-like model of the sRGB gamut built on [`Oklab`](crate::Oklab).
+///
+/// Unlike [`Hsl`](crate::Hsl), colors with the same lightness look equally light.
+///
+/// [Okhsl]: https://bottosson.github.io/posts/colorpicker/#okhsl
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Okhsl<T = f32> {
+    /// Hue.
+    pub h: crate::Hue<T>,
+    /// Saturation (0 to 1).
+    pub s: T,
+    /// Lightness.
+    /// 0 is complete black, 1 is the brightest white.
+    pub l: T,
+}
+
+impl<T: crate::Float> From<crate::Oklab<T>> for Okhsl<T> {
+    fn from(oklab: crate::Oklab<T>) -> Self {
+        let l = crate::oklab::toe(oklab.l);
+
+        if oklab.l <= T::ZERO || oklab.l >= T::ONE {
+            return Self {
+                h: crate::Hue::ZERO,
+                s: T::ZERO,
+                l,
+            };
+        }
+
+        let c = (oklab.a.powi(2) + oklab.b.powi(2)).sqrt();
+        let h = if c == T::ZERO {
+            crate::Hue::ZERO
+        } else {
+            crate::Hue {
+                unnormalized_radians: oklab.b.atan2(oklab.a),
+            }
+        };
+
+        let chromas = Chromas::new(oklab.l, h);
+        let mid = T::from_f64(MID);
+
+        let s = if c < chromas.mid {
+            let k_1 = mid * chromas.zero;
+            let k_2 = T::ONE - k_1 / chromas.mid;
+            let t = c / (k_1 + k_2 * c);
+
+            t * mid
+        } else {
+            let (k_0, k_1, k_2) = chromas.upper_coefficients();
+            let t = (c - k_0) / (k_1 + k_2 * (c - k_0));
+
+            mid + (T::ONE - mid) * t
+        };
+
+        Self { h, s, l }
+    }
+}
+
+/// The saturation at which chroma reaches [`Chromas::mid`].
+pub(crate) const MID: f64 = 0.8;
+
+/// The chromas that saturation is interpolated between for a lightness and hue.
+pub(crate) struct Chromas<T> {
+    /// Sets how quickly chroma grows with saturation near 0, independently of hue.
+    pub(crate) zero: T,
+    /// The chroma of a saturation of 0.8 for this hue.
+    pub(crate) mid: T,
+    /// The chroma of a saturation of 1, on the edge of the sRGB gamut.
+    pub(crate) max: T,
+}
+
+impl<T: crate::Float> Chromas<T> {
+    pub(crate) fn new(l: T, h: crate::Hue<T>) -> Self {
+        let cusp = crate::Oklch::srgb_cusp(h);
+        let max = crate::Oklch::max_srgb_chroma(l, h);
+
+        // Scale the hue-dependent chroma to compensate for the curved top of the gamut.
+        let s_max = cusp.c / cusp.l;
+        let t_max = cusp.c / (T::ONE - cusp.l);
+        let k = max / (l * s_max).min((T::ONE - l) * t_max);
+
+        let (s_mid, t_mid) = mid_slopes(h.unnormalized_radians.cos(), h.unnormalized_radians.sin());
+
+        // Soft minimums give smooth chromas rather than the sharp corner of a triangle.
+        let c_a = l * s_mid;
+        let c_b = (T::ONE - l) * t_mid;
+        let mid = T::from_f64(0.9)
+            * k
+            * (T::ONE / (T::ONE / c_a.powi(4) + T::ONE / c_b.powi(4)))
+                .sqrt()
+                .sqrt();
+
+        let c_a = l * T::from_f64(0.4);
+        let c_b = (T::ONE - l) * T::from_f64(0.8);
+        let zero = (T::ONE / (T::ONE / c_a.powi(2) + T::ONE / c_b.powi(2))).sqrt();
+
+        Self { zero, mid, max }
+    }
+
+    /// The coefficients of the curve from [`Chromas::mid`] to [`Chromas::max`].
+    pub(crate) fn upper_coefficients(&self) -> (T, T, T) {
+        let mid = T::from_f64(MID);
+        let mid_inv = T::ONE / mid;
+
+        let k_0 = self.mid;
+        let k_1 = (T::ONE - mid) * self.mid * self.mid * mid_inv * mid_inv / self.zero;
+        let k_2 = T::ONE - k_1 / (self.max - self.mid);
+
+        (k_0, k_1, k_2)
+    }
+}
+
+/// Slopes of a triangle approximating the gamut that is smoother across hues than the real one,
+/// as fitted by Ottosson.
+fn mid_slopes<T: crate::Float>(a: T, b: T) -> (T, T) {
+    let f = T::from_f64;
+
+    let s = f(0.11516993)
+        + T::ONE
+            / (f(7.44778970)
+                + f(4.15901240) * b
+                + a * (f(-2.19557347)
+                    + f(1.75198401) * b
+                    + a * (f(-2.13704948) - f(10.02301043) * b
+                        + a * (f(-4.24894561) + f(5.38770819) * b + f(4.69891013) * a))));
+
+    let t = f(0.11239642)
+        + T::ONE
+            / (f(1.61320320) - f(0.68124379) * b
+                + a * (f(0.40370612)
+                    + f(0.90148123) * b
+                    + a * (f(-0.27087943)
+                        + f(0.61223990) * b
+                        + a * (f(0.00299215) - f(0.45399568) * b - f(0.14661872) * a))));
+
+    (s, t)
+}
+
+impl<T: crate::Float> crate::ColorSpace for Okhsl<T> {
+    const BLACK: Self = Self {
+        h: crate::Hue::ZERO,
+        s: T::ZERO,
+        l: T::ZERO,
+    };
+
+    const WHITE: Self = Self {
+        h: crate::Hue::ZERO,
+        s: T::ZERO,
+        l: T::ONE,
+    };
+
+    fn in_bounds(self) -> bool {
+        crate::approx_in_range(self.s, T::ZERO..T::ONE)
+            && crate::approx_in_range(self.l, T::ZERO..T::ONE)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ColorSpace, LinearRgb, Oklab, Srgb};
+
+    #[test]
+    fn round_trip() {
+        for &(r, g, b) in &[(0.9, 0.3, 0.6), (0.1, 0.8, 0.2), (0.5, 0.5, 0.55)] {
+            let srgb: Srgb<f64> = Srgb { r, g, b };
+
+            let oklab: Oklab<f64> = crate::convert(LinearRgb::from(srgb));
+            let back = Oklab::from(Okhsl::from(oklab));
+
+            assert!((back.l - oklab.l).abs() < 1e-9);
+            assert!((back.a - oklab.a).abs() < 1e-9);
+            assert!((back.b - oklab.b).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn primaries_are_fully_saturated() {
+        for &(r, g, b) in &[(1.0, 0.0, 0.0), (0.0, 1.0, 0.0), (0.0, 0.0, 1.0)] {
+            let oklab: Oklab<f64> = crate::convert(LinearRgb { r, g, b });
+            assert!((Okhsl::from(oklab).s - 1.0).abs() < 1e-3);
+        }
+    }
+
+    #[test]
+    fn white_and_black() {
+        let white = Okhsl::from(Oklab::<f64>::WHITE);
+        assert!((white.l - 1.0).abs() < 1e-9);
+        assert_eq!(white.s, 0.0);
+
+        assert_eq!(Okhsl::from(Oklab::<f64>::BLACK), Okhsl::BLACK);
+    }
+}
@@ -0,0 +1,130 @@
+/// A color from [Okhsv], an HSV-like model of the sRGB gamut built on [`Oklab`](crate::Oklab).
+///
+/// Unlike [`Hsv`](crate::Hsv), equal steps along each component look roughly equally large.
+///
+/// [Okhsv]: https://bottosson.github.io/posts/colorpicker/#okhsv
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Okhsv<T = f32> {
+    /// Hue.
+    pub h: crate::Hue<T>,
+    /// Saturation (0 to 1).
+    pub s: T,
+    /// Value.
+    /// 0 is complete black, 1 is the brightest color of this hue and saturation.
+    pub v: T,
+}
+
+impl<T: crate::Float> From<crate::Oklab<T>> for Okhsv<T> {
+    fn from(oklab: crate::Oklab<T>) -> Self {
+        if oklab.l <= T::ZERO {
+            return crate::ColorSpace::BLACK;
+        }
+
+        let c = (oklab.a.powi(2) + oklab.b.powi(2)).sqrt();
+        let h = if c == T::ZERO {
+            crate::Hue::ZERO
+        } else {
+            crate::Hue {
+                unnormalized_radians: oklab.b.atan2(oklab.a),
+            }
+        };
+
+        let cusp = crate::Oklch::srgb_cusp(h);
+        let s_max = cusp.c / cusp.l;
+        let t_max = cusp.c / (T::ONE - cusp.l);
+        let s_0 = T::from_f64(0.5);
+        let k = T::ONE - s_0 / s_max;
+
+        // Find the color with a value of 1 on the line from black through this color,
+        // assuming the gamut is a perfect triangle.
+        let t = t_max / (c + oklab.l * t_max);
+        let l_v = t * oklab.l;
+        let c_v = t * c;
+
+        // Undo the compensation for the toe and the curved top of the gamut.
+        let scale_l = scale_l(h, l_v, c_v);
+        let l = crate::oklab::toe(oklab.l / scale_l);
+
+        Self {
+            h,
+            s: (s_0 + t_max) * c_v / (t_max * s_0 + t_max * k * c_v),
+            v: l / l_v,
+        }
+    }
+}
+
+/// The factor scaling a color on the triangular approximation of the gamut onto the real gamut boundary.
+pub(crate) fn scale_l<T: crate::Float>(h: crate::Hue<T>, l_v: T, c_v: T) -> T {
+    let l_vt = crate::oklab::toe_inv(l_v);
+    let c_vt = c_v * l_vt / l_v;
+
+    let rgb: crate::LinearRgb<T> = crate::convert(crate::Oklab::from(crate::Oklch {
+        l: l_vt,
+        c: c_vt,
+        h,
+    }));
+
+    (T::ONE / rgb.r.max(rgb.g).max(rgb.b).max(T::ZERO)).cbrt()
+}
+
+impl<T: crate::Float> crate::ColorSpace for Okhsv<T> {
+    const BLACK: Self = Self {
+        h: crate::Hue::ZERO,
+        s: T::ZERO,
+        v: T::ZERO,
+    };
+
+    const WHITE: Self = Self {
+        h: crate::Hue::ZERO,
+        s: T::ZERO,
+        v: T::ONE,
+    };
+
+    fn in_bounds(self) -> bool {
+        crate::approx_in_range(self.s, T::ZERO..T::ONE)
+            && crate::approx_in_range(self.v, T::ZERO..T::ONE)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ColorSpace, LinearRgb, Oklab, Srgb};
+
+    #[test]
+    fn round_trip() {
+        let srgb: Srgb<f64> = Srgb {
+            r: 0.9,
+            g: 0.3,
+            b: 0.6,
+        };
+
+        let oklab: Oklab<f64> = crate::convert(LinearRgb::from(srgb));
+        let okhsv = Okhsv::from(oklab);
+        let back = Oklab::from(okhsv);
+
+        assert!((back.l - oklab.l).abs() < 1e-9);
+        assert!((back.a - oklab.a).abs() < 1e-9);
+        assert!((back.b - oklab.b).abs() < 1e-9);
+    }
+
+    #[test]
+    fn primaries_are_fully_saturated() {
+        for &(r, g, b) in &[(1.0, 0.0, 0.0), (0.0, 1.0, 0.0), (0.0, 0.0, 1.0)] {
+            let oklab: Oklab<f64> = crate::convert(LinearRgb { r, g, b });
+            let okhsv = Okhsv::from(oklab);
+
+            assert!((okhsv.s - 1.0).abs() < 1e-3);
+            assert!((okhsv.v - 1.0).abs() < 1e-3);
+        }
+    }
+
+    #[test]
+    fn white_and_black() {
+        let white = Okhsv::from(Oklab::<f64>::WHITE);
+        assert!(white.s.abs() < 1e-9);
+        assert!((white.v - 1.0).abs() < 1e-3);
+
+        assert_eq!(Okhsv::from(Oklab::<f64>::BLACK), Okhsv::BLACK);
+    }
+}
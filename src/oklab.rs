@@ -2,44 +2,37 @@
 ///
 /// [Oklab]: https://bottosson.github.io/posts/oklab/
 #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
-pub struct Oklab {
+#[repr(C)]
+pub struct Oklab<T = f32> {
     /// Lightness.
     /// 0 is complete black, 1 is the brightest white.
-    pub l: f32,
+    pub l: T,
     /// Green vs red.
     /// -1 is green, 1 is red.
-    pub a: f32,
+    pub a: T,
     /// Blue vs yellow.
     /// -1 is blue, 1 is yellow.
-    pub b: f32,
+    pub b: T,
 }
 
-const M1: [[f32; 3]; 3] = [
+pub(crate) const M1: crate::matrix::Matrix = [
     [0.8189330101, 0.3618667424, -0.1288597137],
     [0.0329845436, 0.9293118715, 0.0361456387],
     [0.0482003018, 0.2643662691, 0.6338517070],
 ];
 
-const M1_INV: [[f32; 3]; 3] = [
-    [1.2270138511, -0.5577999807, 0.2812561490],
-    [-0.0405801784, 1.1122568696, -0.0716766787],
-    [-0.0763812845, -0.4214819784, 1.5861632204],
-];
+pub(crate) const M1_INV: crate::matrix::Matrix = crate::matrix::inverse(M1);
 
-const M2: [[f32; 3]; 3] = [
+pub(crate) const M2: crate::matrix::Matrix = [
     [0.2104542553, 0.7936177850, -0.0040720468],
     [1.9779984951, -2.4285922050, 0.4505937099],
     [0.0259040371, 0.7827717662, -0.8086757660],
 ];
 
-const M2_INV: [[f32; 3]; 3] = [
-    [0.9999999985, 0.3963377922, 0.2158037581],
-    [1.0000000089, -0.1055613423, -0.0638541748],
-    [1.0000000547, -0.0894841821, -1.2914855379],
-];
+pub(crate) const M2_INV: crate::matrix::Matrix = crate::matrix::inverse(M2);
 
-impl From<crate::Oklch> for Oklab {
-    fn from(oklch: crate::Oklch) -> Self {
+impl<T: crate::Float> From<crate::Oklch<T>> for Oklab<T> {
+    fn from(oklch: crate::Oklch<T>) -> Self {
         Self {
             l: oklch.l,
             a: oklch.c * oklch.h.unnormalized_radians.cos(),
@@ -48,57 +41,155 @@ impl From<crate::Oklch> for Oklab {
     }
 }
 
-impl crate::ColorSpace for Oklab {
+impl<T: crate::Float> From<crate::Okhsv<T>> for Oklab<T> {
+    fn from(okhsv: crate::Okhsv<T>) -> Self {
+        if okhsv.v <= T::ZERO {
+            return crate::ColorSpace::BLACK;
+        }
+
+        let cusp = crate::Oklch::srgb_cusp(okhsv.h);
+        let s_max = cusp.c / cusp.l;
+        let t_max = cusp.c / (T::ONE - cusp.l);
+        let s_0 = T::from_f64(0.5);
+        let k = T::ONE - s_0 / s_max;
+
+        // Find the lightness and chroma as if the gamut were a perfect triangle.
+        let denominator = s_0 + t_max - t_max * k * okhsv.s;
+        let l_v = T::ONE - okhsv.s * s_0 / denominator;
+        let c_v = okhsv.s * t_max * s_0 / denominator;
+        let l = okhsv.v * l_v;
+        let c = okhsv.v * c_v;
+
+        // Compensate for the toe and the curved top of the gamut.
+        let l_new = toe_inv(l);
+        let c = c * l_new / l;
+        let scale_l = crate::okhsv::scale_l(okhsv.h, l_v, c_v);
+
+        Self::from(crate::Oklch {
+            l: l_new * scale_l,
+            c: c * scale_l,
+            h: okhsv.h,
+        })
+    }
+}
+
+impl<T: crate::Float> From<crate::Okhsl<T>> for Oklab<T> {
+    fn from(okhsl: crate::Okhsl<T>) -> Self {
+        let l = toe_inv(okhsl.l);
+
+        if okhsl.l <= T::ZERO || okhsl.l >= T::ONE {
+            return Self {
+                l,
+                a: T::ZERO,
+                b: T::ZERO,
+            };
+        }
+
+        let chromas = crate::okhsl::Chromas::new(l, okhsl.h);
+        let mid = T::from_f64(crate::okhsl::MID);
+
+        let c = if okhsl.s < mid {
+            let t = okhsl.s / mid;
+            let k_1 = mid * chromas.zero;
+            let k_2 = T::ONE - k_1 / chromas.mid;
+
+            t * k_1 / (T::ONE - k_2 * t)
+        } else {
+            let t = (okhsl.s - mid) / (T::ONE - mid);
+            let (k_0, k_1, k_2) = chromas.upper_coefficients();
+
+            k_0 + t * k_1 / (T::ONE - k_2 * t)
+        };
+
+        Self::from(crate::Oklch { l, c, h: okhsl.h })
+    }
+}
+
+const TOE_K1: f64 = 0.206;
+const TOE_K2: f64 = 0.03;
+const TOE_K3: f64 = (1.0 + TOE_K1) / (1.0 + TOE_K2);
+
+/// Ottosson’s estimate of CIE L* (scaled to 0 to 1) from Oklab lightness,
+/// used as the lightness of Okhsv and Okhsl.
+pub(crate) fn toe<T: crate::Float>(l: T) -> T {
+    let [k_1, k_2, k_3] = [TOE_K1, TOE_K2, TOE_K3].map(T::from_f64);
+    let n = k_3 * l - k_1;
+
+    T::from_f64(0.5) * (n + (n * n + T::from_f64(4.0) * k_2 * k_3 * l).sqrt())
+}
+
+/// The inverse of [`toe`].
+pub(crate) fn toe_inv<T: crate::Float>(l: T) -> T {
+    let [k_1, k_2, k_3] = [TOE_K1, TOE_K2, TOE_K3].map(T::from_f64);
+
+    (l * l + k_1 * l) / (k_3 * (l + k_2))
+}
+
+impl<T: crate::Float> crate::ColorSpace for Oklab<T> {
     const BLACK: Self = Self {
-        l: 0.0,
-        a: 0.0,
-        b: 0.0,
+        l: T::ZERO,
+        a: T::ZERO,
+        b: T::ZERO,
     };
 
     const WHITE: Self = Self {
-        l: 1.0,
-        a: 0.0,
-        b: 0.0,
+        l: T::ONE,
+        a: T::ZERO,
+        b: T::ZERO,
     };
 
     fn in_bounds(self) -> bool {
-        crate::approx_in_range(self.l, 0.0..1.0)
-            && crate::approx_in_range(self.a, -1.0..1.0)
-            && crate::approx_in_range(self.b, -1.0..1.0)
+        crate::approx_in_range(self.l, T::ZERO..T::ONE)
+            && crate::approx_in_range(self.a, -T::ONE..T::ONE)
+            && crate::approx_in_range(self.b, -T::ONE..T::ONE)
     }
 }
 
-#[allow(clippy::many_single_char_names)]
-impl crate::CoreColorSpace for Oklab {
-    fn from_xyz(xyz: crate::Xyz) -> Self {
-        let l = M1[0][0] * xyz.x + M1[0][1] * xyz.y + M1[0][2] * xyz.z;
-        let m = M1[1][0] * xyz.x + M1[1][1] * xyz.y + M1[1][2] * xyz.z;
-        let s = M1[2][0] * xyz.x + M1[2][1] * xyz.y + M1[2][2] * xyz.z;
+impl<T: crate::Float> crate::CoreColorSpace for Oklab<T> {
+    type WhitePoint = crate::illuminant::D65;
+    type Component = T;
+
+    const LINEAR_TO_XYZ: [[f64; 3]; 3] = M1_INV;
+    const XYZ_TO_LINEAR: [[f64; 3]; 3] = M1;
 
-        let l_ = l.cbrt();
-        let m_ = m.cbrt();
-        let s_ = s.cbrt();
+    fn from_xyz(xyz: crate::Xyz<crate::illuminant::D65, T>) -> Self {
+        Self::from_linear(crate::matrix::apply(M1, [xyz.x, xyz.y, xyz.z]))
+    }
 
-        let l = M2[0][0] * l_ + M2[0][1] * m_ + M2[0][2] * s_;
-        let a = M2[1][0] * l_ + M2[1][1] * m_ + M2[1][2] * s_;
-        let b = M2[2][0] * l_ + M2[2][1] * m_ + M2[2][2] * s_;
+    fn to_xyz(self) -> crate::Xyz<crate::illuminant::D65, T> {
+        let [x, y, z] = crate::matrix::apply(M1_INV, self.to_linear());
 
-        Self { l, a, b }
+        crate::Xyz::new(x, y, z)
     }
 
-    fn to_xyz(self) -> crate::Xyz {
-        let l_ = M2_INV[0][0] * self.l + M2_INV[0][1] * self.a + M2_INV[0][2] * self.b;
-        let m_ = M2_INV[1][0] * self.l + M2_INV[1][1] * self.a + M2_INV[1][2] * self.b;
-        let s_ = M2_INV[2][0] * self.l + M2_INV[2][1] * self.a + M2_INV[2][2] * self.b;
+    /// Converts the color to approximate cone responses (LMS).
+    fn to_linear(self) -> [T; 3] {
+        let [l_, m_, s_] = crate::matrix::apply(M2_INV, [self.l, self.a, self.b]);
 
-        let l = l_.powi(3);
-        let m = m_.powi(3);
-        let s = s_.powi(3);
+        [l_.powi(3), m_.powi(3), s_.powi(3)]
+    }
 
-        let x = M1_INV[0][0] * l + M1_INV[0][1] * m + M1_INV[0][2] * s;
-        let y = M1_INV[1][0] * l + M1_INV[1][1] * m + M1_INV[1][2] * s;
-        let z = M1_INV[2][0] * l + M1_INV[2][1] * m + M1_INV[2][2] * s;
+    fn from_linear([l, m, s]: [T; 3]) -> Self {
+        let [l, a, b] = crate::matrix::apply(M2, [l.cbrt(), m.cbrt(), s.cbrt()]);
 
-        crate::Xyz { x, y, z }
+        Self { l, a, b }
     }
 }
+
+#[cfg(test)]
+#[test]
+fn f64_round_trip() {
+    use crate::CoreColorSpace;
+
+    let oklab = Oklab {
+        l: 0.7_f64,
+        a: 0.1,
+        b: -0.05,
+    };
+
+    let back = Oklab::from_xyz(oklab.to_xyz());
+
+    assert!((back.l - oklab.l).abs() < 1e-12);
+    assert!((back.a - oklab.a).abs() < 1e-12);
+    assert!((back.b - oklab.b).abs() < 1e-12);
+}
@@ -2,21 +2,21 @@
 ///
 /// [Oklab]: https://bottosson.github.io/posts/oklab/
 #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
-pub struct Oklch {
+pub struct Oklch<T = f32> {
     /// Lightness.
     /// 0 is complete black, 1 is the brightest white.
-    pub l: f32,
+    pub l: T,
     /// Chroma, which is similar to ([but not exactly the same as][chroma_vs_sat]) saturation.
     /// 0 is completely colorless, 1 is the most vivid color.
     ///
     /// [chroma_vs_sat]: https://munsell.com/color-blog/difference-chroma-saturation/
-    pub c: f32,
+    pub c: T,
     /// Hue.
-    pub h: crate::Hue,
+    pub h: crate::Hue<T>,
 }
 
-impl From<crate::Oklab> for Oklch {
-    fn from(oklab: crate::Oklab) -> Self {
+impl<T: crate::Float> From<crate::Oklab<T>> for Oklch<T> {
+    fn from(oklab: crate::Oklab<T>) -> Self {
         Self {
             l: oklab.l,
             c: (oklab.a.powi(2) + oklab.b.powi(2)).sqrt(),
@@ -27,24 +27,21 @@ impl From<crate::Oklab> for Oklch {
     }
 }
 
-impl crate::ColorSpace for Oklch {
+impl<T: crate::Float> crate::ColorSpace for Oklch<T> {
     const BLACK: Self = Self {
-        l: 0.0,
-        c: 0.0,
-        h: crate::Hue {
-            unnormalized_radians: 0.0,
-        },
+        l: T::ZERO,
+        c: T::ZERO,
+        h: crate::Hue::ZERO,
     };
 
     const WHITE: Self = Self {
-        l: 1.0,
-        c: 0.0,
-        h: crate::Hue {
-            unnormalized_radians: 0.0,
-        },
+        l: T::ONE,
+        c: T::ZERO,
+        h: crate::Hue::ZERO,
     };
 
     fn in_bounds(self) -> bool {
-        crate::approx_in_range(self.l, 0.0..1.0) && crate::approx_in_range(self.c, 0.0..1.0)
+        crate::approx_in_range(self.l, T::ZERO..T::ONE)
+            && crate::approx_in_range(self.c, T::ZERO..T::ONE)
     }
 }
@@ -0,0 +1,78 @@
+/// The [ProPhoto RGB] color space, for use with [`LinearRgbIn`](crate::LinearRgbIn) and [`EncodedRgbIn`](crate::EncodedRgbIn).
+///
+/// ProPhoto RGB is defined relative to [D50](crate::illuminant::D50).
+///
+/// [ProPhoto RGB]: https://en.wikipedia.org/wiki/ProPhoto_RGB_color_space
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct ProPhotoRgbSpace;
+
+impl crate::RgbSpaceDefinition for ProPhotoRgbSpace {
+    type WhitePoint = crate::illuminant::D50;
+
+    const PRIMARIES: [crate::Chromaticity; 3] = [
+        crate::Chromaticity {
+            x: 0.7347,
+            y: 0.2653,
+        },
+        crate::Chromaticity {
+            x: 0.1596,
+            y: 0.8404,
+        },
+        crate::Chromaticity {
+            x: 0.0366,
+            y: 0.0001,
+        },
+    ];
+
+    const TRANSFER: crate::TransferFunction = crate::TransferFunction::ProPhotoRgb;
+}
+
+/// A [ProPhoto RGB] color.
+///
+/// [ProPhoto RGB]: https://en.wikipedia.org/wiki/ProPhoto_RGB_color_space
+pub type ProPhotoRgb<T = f32> = crate::EncodedRgbIn<ProPhotoRgbSpace, T>;
+
+/// A [ProPhoto RGB] color without gamma correction.
+///
+/// [ProPhoto RGB]: https://en.wikipedia.org/wiki/ProPhoto_RGB_color_space
+pub type LinearProPhotoRgb<T = f32> = crate::LinearRgbIn<ProPhotoRgbSpace, T>;
+
+#[cfg(test)]
+#[test]
+fn round_trip() {
+    use crate::{CoreColorSpace, LinearProPhotoRgb};
+
+    let color: ProPhotoRgb = ProPhotoRgb::new(0.25, 0.5, 0.75);
+
+    let xyz = LinearProPhotoRgb::from(color).to_xyz();
+    let back = ProPhotoRgb::from(LinearProPhotoRgb::from_xyz(xyz));
+
+    assert!((back.r - color.r).abs() < 0.001);
+    assert!((back.g - color.g).abs() < 0.001);
+    assert!((back.b - color.b).abs() < 0.001);
+}
+
+#[cfg(test)]
+#[test]
+fn white_is_adapted_to_d65() {
+    use crate::{ColorSpace, LinearProPhotoRgb, LinearRgb};
+
+    let white: LinearRgb = crate::convert(LinearProPhotoRgb::WHITE);
+
+    assert!((white.r - 1.0).abs() < 0.001);
+    assert!((white.g - 1.0).abs() < 0.001);
+    assert!((white.b - 1.0).abs() < 0.001);
+}
+
+#[cfg(test)]
+#[test]
+fn white_is_d50() {
+    use crate::{ColorSpace, CoreColorSpace, LinearProPhotoRgb, WhitePoint};
+
+    let white = LinearProPhotoRgb::<f64>::WHITE.to_xyz();
+    let [x, y, z] = crate::illuminant::D50::XYZ;
+
+    assert!((white.x - x).abs() < 1e-12);
+    assert!((white.y - y).abs() < 1e-12);
+    assert!((white.z - z).abs() < 1e-12);
+}
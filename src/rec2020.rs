@@ -0,0 +1,27 @@
+/// The [Rec. 2020] color space, for use with [`LinearRgbIn`](crate::LinearRgbIn) and [`EncodedRgbIn`](crate::EncodedRgbIn).
+///
+/// [Rec. 2020]: https://www.itu.int/rec/R-REC-BT.2020
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Rec2020Space;
+
+impl crate::RgbSpaceDefinition for Rec2020Space {
+    type WhitePoint = crate::illuminant::D65;
+
+    const PRIMARIES: [crate::Chromaticity; 3] = [
+        crate::Chromaticity { x: 0.708, y: 0.292 },
+        crate::Chromaticity { x: 0.17, y: 0.797 },
+        crate::Chromaticity { x: 0.131, y: 0.046 },
+    ];
+
+    const TRANSFER: crate::TransferFunction = crate::TransferFunction::Rec2020;
+}
+
+/// A [Rec. 2020] color.
+///
+/// [Rec. 2020]: https://www.itu.int/rec/R-REC-BT.2020
+pub type Rec2020<T = f32> = crate::EncodedRgbIn<Rec2020Space, T>;
+
+/// A [Rec. 2020] color without gamma correction.
+///
+/// [Rec. 2020]: https://www.itu.int/rec/R-REC-BT.2020
+pub type LinearRec2020<T = f32> = crate::LinearRgbIn<Rec2020Space, T>;
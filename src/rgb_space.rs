@@ -0,0 +1,197 @@
+use crate::matrix::{self, Matrix};
+use std::marker::PhantomData;
+
+/// A description of an RGB color space in terms of its primaries, white point and transfer function.
+///
+/// The matrices converting to and from XYZ are derived when the `RgbSpace` is created,
+/// which can happen in a const context:
+///
+/// ```
+/// use tincture::illuminant::D65;
+/// use tincture::{Chromaticity, RgbSpace, TransferFunction, WhitePoint};
+///
+/// const DISPLAY_P3: RgbSpace = RgbSpace::new(
+///     Chromaticity { x: 0.680, y: 0.320 },
+///     Chromaticity { x: 0.265, y: 0.690 },
+///     Chromaticity { x: 0.150, y: 0.060 },
+///     D65::CHROMATICITY,
+///     TransferFunction::Srgb,
+/// );
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct RgbSpace {
+    to_xyz: Matrix,
+    from_xyz: Matrix,
+    transfer: TransferFunction,
+}
+
+impl RgbSpace {
+    /// Creates a new `RgbSpace` from the chromaticities of its red, green and blue primaries,
+    /// the chromaticity of its white point and its transfer function.
+    pub const fn new(
+        red: crate::Chromaticity,
+        green: crate::Chromaticity,
+        blue: crate::Chromaticity,
+        white: crate::Chromaticity,
+        transfer: TransferFunction,
+    ) -> Self {
+        let [red_x, red_y, red_z] = red.to_xyz();
+        let [green_x, green_y, green_z] = green.to_xyz();
+        let [blue_x, blue_y, blue_z] = blue.to_xyz();
+
+        let primaries = [
+            [red_x, green_x, blue_x],
+            [red_y, green_y, blue_y],
+            [red_z, green_z, blue_z],
+        ];
+
+        // Scale each primary so that full intensity on all three channels produces the white point.
+        let scale = matrix::mul_vector(matrix::inverse(primaries), white.to_xyz());
+        let to_xyz = matrix::mul(primaries, matrix::diagonal(scale));
+
+        Self {
+            to_xyz,
+            from_xyz: matrix::inverse(to_xyz),
+            transfer,
+        }
+    }
+
+    /// The matrix converting linear RGB components to XYZ.
+    pub const fn to_xyz_matrix(&self) -> [[f64; 3]; 3] {
+        self.to_xyz
+    }
+
+    /// The matrix converting XYZ to linear RGB components.
+    pub const fn from_xyz_matrix(&self) -> [[f64; 3]; 3] {
+        self.from_xyz
+    }
+
+    /// The transfer function of the color space.
+    pub const fn transfer(&self) -> TransferFunction {
+        self.transfer
+    }
+}
+
+/// The transfer function (also known as the gamma curve) of an RGB color space.
+#[derive(Debug, Clone, Copy)]
+pub enum TransferFunction {
+    /// No gamma correction.
+    Linear,
+    /// The piecewise curve used by sRGB and Display P3.
+    Srgb,
+    /// The piecewise curve used by Rec. 2020.
+    Rec2020,
+    /// The piecewise curve used by ProPhoto RGB.
+    ProPhotoRgb,
+    /// A pure power curve with the given gamma, such as 563/256 for Adobe RGB (1998).
+    Gamma(f64),
+    /// A custom pair of functions, which are evaluated at full precision.
+    Custom {
+        /// Converts a linear component to its gamma-corrected form.
+        encode: fn(f64) -> f64,
+        /// Converts a gamma-corrected component to its linear form.
+        decode: fn(f64) -> f64,
+    },
+}
+
+impl TransferFunction {
+    /// Converts a linear component to its gamma-corrected form.
+    pub fn encode<T: crate::Float>(self, n: T) -> T {
+        match self {
+            Self::Linear => n,
+            Self::Srgb => crate::transfer::srgb_encode(n),
+            Self::Rec2020 => crate::transfer::rec2020_encode(n),
+            Self::ProPhotoRgb => crate::transfer::pro_photo_rgb_encode(n),
+            Self::Gamma(gamma) => crate::transfer::gamma_encode(n, gamma),
+            Self::Custom { encode, .. } => T::from_f64(encode(n.to_f64())),
+        }
+    }
+
+    /// Converts a gamma-corrected component to its linear form.
+    pub fn decode<T: crate::Float>(self, n: T) -> T {
+        match self {
+            Self::Linear => n,
+            Self::Srgb => crate::transfer::srgb_decode(n),
+            Self::Rec2020 => crate::transfer::rec2020_decode(n),
+            Self::ProPhotoRgb => crate::transfer::pro_photo_rgb_decode(n),
+            Self::Gamma(gamma) => crate::transfer::gamma_decode(n, gamma),
+            Self::Custom { decode, .. } => T::from_f64(decode(n.to_f64())),
+        }
+    }
+}
+
+/// A type that defines an RGB color space, for use with [`LinearRgbIn`](crate::LinearRgbIn)
+/// and [`EncodedRgbIn`](crate::EncodedRgbIn).
+///
+/// The [`RgbSpace`] is derived from the primaries, the transfer function and the chromaticity of
+/// [`WhitePoint`](RgbSpaceDefinition::WhitePoint),
+/// so white always maps onto the white point that conversions adapt from.
+///
+/// ```
+/// use tincture::illuminant::D50;
+/// use tincture::{Chromaticity, LinearRgbIn, Oklab, RgbSpaceDefinition, TransferFunction};
+///
+/// struct Projector;
+///
+/// impl RgbSpaceDefinition for Projector {
+///     type WhitePoint = D50;
+///
+///     const PRIMARIES: [Chromaticity; 3] = [
+///         Chromaticity { x: 0.660, y: 0.330 },
+///         Chromaticity { x: 0.285, y: 0.640 },
+///         Chromaticity { x: 0.150, y: 0.070 },
+///     ];
+///
+///     const TRANSFER: TransferFunction = TransferFunction::Gamma(2.4);
+/// }
+///
+/// let color: LinearRgbIn<Projector> = tincture::convert(Oklab { l: 0.5, a: 0.1, b: 0.0 });
+/// ```
+pub trait RgbSpaceDefinition {
+    /// The white point of the color space.
+    type WhitePoint: crate::WhitePoint;
+
+    /// The chromaticities of the red, green and blue primaries.
+    const PRIMARIES: [crate::Chromaticity; 3];
+
+    /// The transfer function of the color space.
+    const TRANSFER: TransferFunction;
+}
+
+/// The [`RgbSpace`] described by the definition `S`, derived at compile time.
+pub(crate) struct SpaceOf<S>(PhantomData<S>);
+
+impl<S: RgbSpaceDefinition> SpaceOf<S> {
+    pub(crate) const SPACE: RgbSpace = {
+        let [red, green, blue] = S::PRIMARIES;
+        let white = <S::WhitePoint as crate::WhitePoint>::CHROMATICITY;
+
+        RgbSpace::new(red, green, blue, white, S::TRANSFER)
+    };
+}
+
+#[cfg(test)]
+#[test]
+fn derives_srgb_matrix() {
+    use crate::Chromaticity;
+
+    let srgb = RgbSpace::new(
+        Chromaticity { x: 0.64, y: 0.33 },
+        Chromaticity { x: 0.30, y: 0.60 },
+        Chromaticity { x: 0.15, y: 0.06 },
+        <crate::illuminant::D65 as crate::WhitePoint>::CHROMATICITY,
+        TransferFunction::Srgb,
+    );
+
+    let expected = [
+        [0.4124564, 0.3575761, 0.1804375],
+        [0.2126729, 0.7151522, 0.0721750],
+        [0.0193339, 0.1191920, 0.9503041],
+    ];
+
+    for (row, expected_row) in srgb.to_xyz_matrix().iter().zip(&expected) {
+        for (n, expected_n) in row.iter().zip(expected_row) {
+            assert!((n - expected_n).abs() < 0.0005);
+        }
+    }
+}
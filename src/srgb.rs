@@ -1,58 +1,130 @@
 /// An sRGB color.
 #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
-pub struct Srgb {
+pub struct Srgb<T = f32> {
     /// Red (0 to 1).
-    pub r: f32,
+    pub r: T,
     /// Green (0 to 1).
-    pub g: f32,
+    pub g: T,
     /// Blue (0 to 1).
-    pub b: f32,
+    pub b: T,
 }
 
-impl From<crate::LinearRgb> for Srgb {
-    fn from(linear: crate::LinearRgb) -> Self {
-        let from_linear = |n: f32| {
-            if n <= 0.0031308 {
-                n * 12.92
-            } else {
-                n.powf(1.0 / 2.4) * 1.055 - 0.055
-            }
+impl<T: crate::Float> From<crate::LinearRgb<T>> for Srgb<T> {
+    fn from(linear: crate::LinearRgb<T>) -> Self {
+        Self {
+            r: crate::transfer::srgb_encode(linear.r),
+            g: crate::transfer::srgb_encode(linear.g),
+            b: crate::transfer::srgb_encode(linear.b),
+        }
+    }
+}
+
+impl<T: crate::Float> From<crate::Hsl<T>> for Srgb<T> {
+    fn from(hsl: crate::Hsl<T>) -> Self {
+        let hue = hsl.h.to_degrees();
+        let a = hsl.s * hsl.l.min(T::ONE - hsl.l);
+
+        let f = |n: f64| {
+            let k = (T::from_f64(n) + hue / T::from_f64(30.0)).rem_euclid(T::from_f64(12.0));
+            let t = (k - T::from_f64(3.0)).min(T::from_f64(9.0) - k);
+            hsl.l - a * t.clamp(-T::ONE, T::ONE)
         };
 
         Self {
-            r: from_linear(linear.r),
-            g: from_linear(linear.g),
-            b: from_linear(linear.b),
+            r: f(0.0),
+            g: f(8.0),
+            b: f(4.0),
         }
     }
 }
 
-impl crate::ColorSpace for Srgb {
+impl<T: crate::Float> From<crate::Hsv<T>> for Srgb<T> {
+    fn from(hsv: crate::Hsv<T>) -> Self {
+        let hue = hsv.h.to_degrees();
+
+        let f = |n: f64| {
+            let k = (T::from_f64(n) + hue / T::from_f64(60.0)).rem_euclid(T::from_f64(6.0));
+            let t = k.min(T::from_f64(4.0) - k);
+            hsv.v - hsv.v * hsv.s * t.clamp(T::ZERO, T::ONE)
+        };
+
+        Self {
+            r: f(5.0),
+            g: f(3.0),
+            b: f(1.0),
+        }
+    }
+}
+
+impl<T: crate::Float> From<crate::Hwb<T>> for Srgb<T> {
+    fn from(hwb: crate::Hwb<T>) -> Self {
+        // Whiteness and blackness that add up to more than 1 produce a shade of grey.
+        if hwb.w + hwb.b >= T::ONE {
+            let grey = hwb.w / (hwb.w + hwb.b);
+
+            return Self {
+                r: grey,
+                g: grey,
+                b: grey,
+            };
+        }
+
+        let pure = Self::from(crate::Hsl {
+            h: hwb.h,
+            s: T::ONE,
+            l: T::from_f64(0.5),
+        });
+
+        let scale = |n: T| n * (T::ONE - hwb.w - hwb.b) + hwb.w;
+
+        Self {
+            r: scale(pure.r),
+            g: scale(pure.g),
+            b: scale(pure.b),
+        }
+    }
+}
+
+impl<T: crate::Float> crate::ColorSpace for Srgb<T> {
     const BLACK: Self = Self {
-        r: 0.0,
-        g: 0.0,
-        b: 0.0,
+        r: T::ZERO,
+        g: T::ZERO,
+        b: T::ZERO,
     };
 
     const WHITE: Self = Self {
-        r: 1.0,
-        g: 1.0,
-        b: 1.0,
+        r: T::ONE,
+        g: T::ONE,
+        b: T::ONE,
     };
 
     fn in_bounds(self) -> bool {
-        crate::approx_in_range(self.r, 0.0..1.0)
-            && crate::approx_in_range(self.g, 0.0..1.0)
-            && crate::approx_in_range(self.b, 0.0..1.0)
+        crate::approx_in_range(self.r, T::ZERO..T::ONE)
+            && crate::approx_in_range(self.g, T::ZERO..T::ONE)
+            && crate::approx_in_range(self.b, T::ZERO..T::ONE)
     }
 }
 
-impl crate::Hex for Srgb {
-    fn components(self) -> (f32, f32, f32) {
+impl<T: crate::Float> crate::Hex<T> for Srgb<T> {
+    fn components(self) -> (T, T, T) {
         (self.r, self.g, self.b)
     }
 }
 
+impl<T: crate::Float> crate::FromHex<T> for Srgb<T> {
+    fn from_components((r, g, b): (T, T, T)) -> Self {
+        Self { r, g, b }
+    }
+}
+
+impl<T: crate::Float> std::str::FromStr for Srgb<T> {
+    type Err = crate::ParseHexError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        crate::FromHex::from_hex_str(s)
+    }
+}
+
 #[cfg(test)]
 #[test]
 fn hex() {
@@ -0,0 +1,254 @@
+//! The boundary of the sRGB gamut in Oklab, computed analytically using [Björn Ottosson’s method][gamut_clipping].
+//!
+//! [gamut_clipping]: https://bottosson.github.io/posts/gamutclipping/
+
+use crate::converter::Plan;
+use crate::matrix::Matrix;
+use crate::{Float, Hue, LinearRgb, Oklab, Oklch};
+
+/// The cusp of the sRGB gamut for a hue:
+/// the lightness and chroma of the most vivid sRGB color with that hue.
+///
+/// For a given hue the sRGB gamut in Oklch is roughly a triangle
+/// with corners at black, white and the cusp.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Cusp<T = f32> {
+    /// Lightness.
+    pub l: T,
+    /// Chroma.
+    pub c: T,
+}
+
+const LMS_TO_LINEAR_RGB: Matrix = Plan::<Oklab, LinearRgb>::MATRIX;
+
+impl<T: Float> Oklch<T> {
+    /// The cusp of the sRGB gamut for the hue `h`.
+    ///
+    /// ```
+    /// use tincture::{Hue, Oklch};
+    ///
+    /// let red: Hue = Hue::from_degrees(29.23).unwrap();
+    /// let cusp = Oklch::srgb_cusp(red);
+    ///
+    /// // The cusp of red’s hue is pure sRGB red.
+    /// assert!((cusp.l - 0.628).abs() < 0.001);
+    /// assert!((cusp.c - 0.258).abs() < 0.001);
+    /// ```
+    pub fn srgb_cusp(h: Hue<T>) -> Cusp<T> {
+        let (a, b) = direction(h);
+        let saturation = max_saturation(a, b);
+
+        // The color with the maximum saturation at a lightness of 1 is outside the gamut,
+        // but scaling it down so its largest component is 1 gives the cusp.
+        let lms = lms(T::ONE, saturation, a, b).map(|n| n.powi(3));
+        let [r, g, b] = crate::matrix::apply(LMS_TO_LINEAR_RGB, lms);
+        let l = (T::ONE / r.max(g).max(b)).cbrt();
+
+        Cusp {
+            l,
+            c: l * saturation,
+        }
+    }
+
+    /// The maximum chroma of a color with lightness `l` and hue `h` that is in the sRGB gamut.
+    ///
+    /// Returns 0 if `l` is at most 0 or at least 1.
+    ///
+    /// ```
+    /// use tincture::{ColorSpace, Hue, LinearRgb, Oklab, Oklch, Srgb};
+    ///
+    /// let h: Hue = Hue::from_degrees(250.0).unwrap();
+    /// let c = Oklch::max_srgb_chroma(0.7, h);
+    ///
+    /// let linear_rgb: LinearRgb = tincture::convert(Oklab::from(Oklch { l: 0.7, c, h }));
+    /// assert!(Srgb::from(linear_rgb).in_bounds());
+    /// ```
+    pub fn max_srgb_chroma(l: T, h: Hue<T>) -> T {
+        if l <= T::ZERO || l >= T::ONE {
+            return T::ZERO;
+        }
+
+        let cusp = Self::srgb_cusp(h);
+
+        // Below the cusp the gamut boundary is a straight line to black.
+        if l <= cusp.l {
+            return cusp.c * l / cusp.l;
+        }
+
+        // Above the cusp the boundary curves on its way to white,
+        // so refine the straight line from the cusp to white with Halley’s method.
+        let (a, b) = direction(h);
+        let mut c = cusp.c * (T::ONE - l) / (T::ONE - cusp.l);
+
+        for _ in 0..2 {
+            c += chroma_step(l, c, a, b);
+        }
+
+        c
+    }
+}
+
+/// The direction of a hue in the a-b plane.
+fn direction<T: Float>(h: Hue<T>) -> (T, T) {
+    (h.unnormalized_radians.cos(), h.unnormalized_radians.sin())
+}
+
+/// The nonlinear cone responses of the color with lightness `l` and chroma `c` in the direction `(a, b)`.
+fn lms<T: Float>(l: T, c: T, a: T, b: T) -> [T; 3] {
+    crate::matrix::apply(crate::oklab::M2_INV, [l, c * a, c * b])
+}
+
+const RED_SATURATION: [f64; 5] = [1.19086277, 1.76576728, 0.59662641, 0.75515197, 0.56771245];
+const GREEN_SATURATION: [f64; 5] = [0.73956515, -0.45954404, 0.08285427, 0.12541070, 0.14503204];
+const BLUE_SATURATION: [f64; 5] = [
+    1.35733652,
+    -0.00915799,
+    -1.15130210,
+    -0.50559606,
+    0.00692167,
+];
+
+/// The chroma divided by lightness at which a hue first leaves the sRGB gamut.
+fn max_saturation<T: Float>(a: T, b: T) -> T {
+    // One of red, green or blue reaches zero first depending on the hue.
+    // A polynomial fitted by Ottosson gives an initial guess for it.
+    let (k, channel) = if a * T::from_f64(-1.88170328) - b * T::from_f64(0.80936493) > T::ONE {
+        (RED_SATURATION, 0)
+    } else if a * T::from_f64(1.81444104) - b * T::from_f64(1.19445276) > T::ONE {
+        (GREEN_SATURATION, 1)
+    } else {
+        (BLUE_SATURATION, 2)
+    };
+
+    let [k0, k1, k2, k3, k4] = k.map(T::from_f64);
+    let mut saturation = k0 + k1 * a + k2 * b + k3 * a * a + k4 * a * b;
+
+    // Refine the guess with Halley’s method on the channel reaching zero.
+    let weights = LMS_TO_LINEAR_RGB[channel].map(T::from_f64);
+    let [k_l, k_m, k_s] = lms(T::ZERO, T::ONE, a, b);
+
+    for _ in 0..2 {
+        let [l_, m_, s_] = lms(T::ONE, saturation, a, b);
+
+        let f = |[l, m, s]: [T; 3]| weights[0] * l + weights[1] * m + weights[2] * s;
+        let f0 = f([l_.powi(3), m_.powi(3), s_.powi(3)]);
+        let three = T::from_f64(3.0);
+        let f1 = f([
+            three * k_l * l_ * l_,
+            three * k_m * m_ * m_,
+            three * k_s * s_ * s_,
+        ]);
+        let six = T::from_f64(6.0);
+        let f2 = f([
+            six * k_l * k_l * l_,
+            six * k_m * k_m * m_,
+            six * k_s * k_s * s_,
+        ]);
+
+        saturation -= f0 * f1 / (f1 * f1 - T::from_f64(0.5) * f0 * f2);
+    }
+
+    saturation
+}
+
+/// One step of Halley’s method toward the chroma at which the color with lightness `l` leaves the sRGB gamut
+/// by one of its components exceeding 1.
+fn chroma_step<T: Float>(l: T, c: T, a: T, b: T) -> T {
+    let [k_l, k_m, k_s] = lms(T::ZERO, T::ONE, a, b);
+    let [l_, m_, s_] = lms(l, c, a, b);
+
+    let three = T::from_f64(3.0);
+    let six = T::from_f64(6.0);
+    let lms = [l_.powi(3), m_.powi(3), s_.powi(3)];
+    let lms_dc = [
+        three * k_l * l_ * l_,
+        three * k_m * m_ * m_,
+        three * k_s * s_ * s_,
+    ];
+    let lms_dc2 = [
+        six * k_l * k_l * l_,
+        six * k_m * k_m * m_,
+        six * k_s * k_s * s_,
+    ];
+
+    let mut step: Option<T> = None;
+
+    for row in &LMS_TO_LINEAR_RGB {
+        let weights = row.map(T::from_f64);
+        let f = |[l, m, s]: [T; 3]| weights[0] * l + weights[1] * m + weights[2] * s;
+
+        let f0 = f(lms) - T::ONE;
+        let f1 = f(lms_dc);
+        let f2 = f(lms_dc2);
+
+        // Only components that are increasing with chroma can leave the gamut by exceeding 1.
+        let u = f1 / (f1 * f1 - T::from_f64(0.5) * f0 * f2);
+        if u >= T::ZERO {
+            let candidate = -f0 * u;
+            step = Some(step.map_or(candidate, |step| step.min(candidate)));
+        }
+    }
+
+    step.unwrap_or(T::ZERO)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn in_gamut(l: f64, c: f64, h: Hue<f64>) -> bool {
+        let rgb: LinearRgb<f64> = crate::convert(Oklab::from(Oklch { l, c, h }));
+        [rgb.r, rgb.g, rgb.b]
+            .iter()
+            .all(|n| (-1e-9..=1.0 + 1e-9).contains(n))
+    }
+
+    fn bisect_max_chroma(l: f64, h: Hue<f64>) -> f64 {
+        let mut min = 0.0;
+        let mut max = 0.5;
+
+        while max - min > 1e-10 {
+            let mid = (min + max) / 2.0;
+            if in_gamut(l, mid, h) {
+                min = mid;
+            } else {
+                max = mid;
+            }
+        }
+
+        min
+    }
+
+    #[test]
+    fn matches_bisection() {
+        for degrees in (0..360).step_by(10) {
+            let h = Hue::from_degrees(f64::from(degrees)).unwrap();
+
+            for &l in &[0.1, 0.3, 0.5, 0.7, 0.9, 0.97] {
+                let expected = bisect_max_chroma(l, h);
+                let c = Oklch::max_srgb_chroma(l, h);
+                assert!((c - expected).abs() < 1e-6, "{}° at {}", degrees, l);
+            }
+        }
+    }
+
+    #[test]
+    fn cusp_touches_two_faces() {
+        for degrees in (0..360).step_by(10) {
+            let h = Hue::from_degrees(f64::from(degrees)).unwrap();
+            let cusp = Oklch::srgb_cusp(h);
+
+            let rgb: LinearRgb<f64> = crate::convert(Oklab::from(Oklch {
+                l: cusp.l,
+                c: cusp.c,
+                h,
+            }));
+
+            let components = [rgb.r, rgb.g, rgb.b];
+            let max = components.iter().cloned().fold(f64::MIN, f64::max);
+            let min = components.iter().cloned().fold(f64::MAX, f64::min);
+            assert!((max - 1.0).abs() < 1e-9);
+            assert!(min.abs() < 1e-9);
+        }
+    }
+}
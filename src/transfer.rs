@@ -0,0 +1,81 @@
+//! Transfer functions (also known as gamma curves) used by the gamma-corrected RGB color spaces.
+//!
+//! `encode` converts a linear component to its gamma-corrected form, and `decode` does the opposite.
+
+use crate::Float;
+
+pub(crate) fn srgb_encode<T: Float>(n: T) -> T {
+    if n <= T::from_f64(0.0031308) {
+        n * T::from_f64(12.92)
+    } else {
+        n.powf(T::from_f64(1.0 / 2.4)) * T::from_f64(1.055) - T::from_f64(0.055)
+    }
+}
+
+pub(crate) fn srgb_decode<T: Float>(n: T) -> T {
+    if n <= T::from_f64(0.04045) {
+        n / T::from_f64(12.92)
+    } else {
+        ((n + T::from_f64(0.055)) / T::from_f64(1.055)).powf(T::from_f64(2.4))
+    }
+}
+
+const REC2020_ALPHA: f64 = 1.09929682680944;
+const REC2020_BETA: f64 = 0.018053968510807;
+
+pub(crate) fn rec2020_encode<T: Float>(n: T) -> T {
+    if n < T::from_f64(REC2020_BETA) {
+        n * T::from_f64(4.5)
+    } else {
+        T::from_f64(REC2020_ALPHA) * n.powf(T::from_f64(0.45)) - T::from_f64(REC2020_ALPHA - 1.0)
+    }
+}
+
+pub(crate) fn rec2020_decode<T: Float>(n: T) -> T {
+    if n < T::from_f64(REC2020_BETA * 4.5) {
+        n / T::from_f64(4.5)
+    } else {
+        ((n + T::from_f64(REC2020_ALPHA - 1.0)) / T::from_f64(REC2020_ALPHA))
+            .powf(T::from_f64(1.0 / 0.45))
+    }
+}
+
+pub(crate) fn gamma_encode<T: Float>(n: T, gamma: f64) -> T {
+    n.abs().powf(T::from_f64(1.0 / gamma)).copysign(n)
+}
+
+pub(crate) fn gamma_decode<T: Float>(n: T, gamma: f64) -> T {
+    n.abs().powf(T::from_f64(gamma)).copysign(n)
+}
+
+const PRO_PHOTO_RGB_THRESHOLD: f64 = 1.0 / 512.0;
+
+pub(crate) fn pro_photo_rgb_encode<T: Float>(n: T) -> T {
+    if n < T::from_f64(PRO_PHOTO_RGB_THRESHOLD) {
+        n * T::from_f64(16.0)
+    } else {
+        n.powf(T::from_f64(1.0 / 1.8))
+    }
+}
+
+pub(crate) fn pro_photo_rgb_decode<T: Float>(n: T) -> T {
+    if n < T::from_f64(PRO_PHOTO_RGB_THRESHOLD * 16.0) {
+        n / T::from_f64(16.0)
+    } else {
+        n.powf(T::from_f64(1.8))
+    }
+}
+
+const PQ_M1: f64 = 2610.0 / 16384.0;
+const PQ_M2: f64 = 2523.0 / 4096.0 * 128.0;
+const PQ_C1: f64 = 3424.0 / 4096.0;
+const PQ_C2: f64 = 2413.0 / 4096.0 * 32.0;
+const PQ_C3: f64 = 2392.0 / 4096.0 * 32.0;
+
+/// The perceptual quantizer from SMPTE ST 2084, where 1 is 10,000 cd/m².
+pub(crate) fn pq_encode<T: Float>(n: T) -> T {
+    let n = n.max(T::ZERO).powf(T::from_f64(PQ_M1));
+
+    ((T::from_f64(PQ_C1) + T::from_f64(PQ_C2) * n) / (T::ONE + T::from_f64(PQ_C3) * n))
+        .powf(T::from_f64(PQ_M2))
+}
@@ -0,0 +1,114 @@
+//! Contrast as defined by the [Web Content Accessibility Guidelines (WCAG) 2.1][wcag].
+//!
+//! ```
+//! use tincture::wcag::{self, Level, TextSize};
+//! use tincture::{LinearRgb, Srgb};
+//!
+//! let text: LinearRgb = LinearRgb::from(Srgb { r: 0.4, g: 0.4, b: 0.4 });
+//! let background: LinearRgb = LinearRgb::from(Srgb { r: 1.0, g: 1.0, b: 1.0 });
+//!
+//! assert!((wcag::contrast_ratio(text, background) - 5.74).abs() < 0.01);
+//! assert_eq!(wcag::level(text, background, TextSize::Normal), Some(Level::Aa));
+//! assert_eq!(wcag::level(text, background, TextSize::Large), Some(Level::Aaa));
+//! ```
+//!
+//! [wcag]: https://www.w3.org/TR/WCAG21/#contrast-minimum
+
+use crate::{CoreColorSpace, Float};
+
+/// The relative luminance of a color: its Y in [`Xyz`](crate::Xyz) relative to D65,
+/// from 0 for black to 1 for white.
+pub fn relative_luminance<C: CoreColorSpace>(color: C) -> C::Component {
+    let xyz: crate::Xyz<crate::illuminant::D65, C::Component> = crate::convert(color);
+    xyz.y
+}
+
+/// The contrast ratio between two colors, from 1 for identical colors to 21 for black and white.
+///
+/// The order of the colors does not matter.
+pub fn contrast_ratio<C: CoreColorSpace>(a: C, b: C) -> C::Component {
+    let a = relative_luminance(a);
+    let b = relative_luminance(b);
+    let offset = C::Component::from_f64(0.05);
+
+    (a.max(b) + offset) / (a.min(b) + offset)
+}
+
+/// The size of text, which changes the contrast it needs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TextSize {
+    /// Text smaller than large text.
+    Normal,
+    /// Text that is at least 18 point, or at least 14 point and bold.
+    Large,
+}
+
+/// A WCAG conformance level for contrast.
+///
+/// Levels are ordered from least to most strict.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Level {
+    /// Success criterion 1.4.3, Contrast (Minimum).
+    Aa,
+    /// Success criterion 1.4.6, Contrast (Enhanced).
+    Aaa,
+}
+
+impl Level {
+    /// The lowest contrast ratio that text of `size` needs to meet the level.
+    pub fn minimum_ratio(self, size: TextSize) -> f64 {
+        match (self, size) {
+            (Self::Aa, TextSize::Normal) => 4.5,
+            (Self::Aa, TextSize::Large) => 3.0,
+            (Self::Aaa, TextSize::Normal) => 7.0,
+            (Self::Aaa, TextSize::Large) => 4.5,
+        }
+    }
+}
+
+/// The strictest level that text of `size` in the color `foreground` on `background` meets,
+/// or `None` if it does not meet any.
+pub fn level<C: CoreColorSpace>(foreground: C, background: C, size: TextSize) -> Option<Level> {
+    let ratio = contrast_ratio(foreground, background).to_f64();
+
+    [Level::Aaa, Level::Aa]
+        .iter()
+        .copied()
+        .find(|level| ratio >= level.minimum_ratio(size))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ColorSpace, LinearRgb, Srgb};
+
+    #[test]
+    fn black_on_white() {
+        let ratio = contrast_ratio(LinearRgb::<f64>::BLACK, LinearRgb::WHITE);
+
+        assert!((ratio - 21.0).abs() < 1e-3);
+        assert_eq!(
+            level(LinearRgb::<f64>::BLACK, LinearRgb::WHITE, TextSize::Normal),
+            Some(Level::Aaa),
+        );
+    }
+
+    #[test]
+    fn grey_on_white() {
+        let grey: Srgb<f64> = Srgb {
+            r: 119.0 / 255.0,
+            g: 119.0 / 255.0,
+            b: 119.0 / 255.0,
+        };
+
+        let grey = LinearRgb::from(grey);
+        let ratio = contrast_ratio(grey, LinearRgb::WHITE);
+
+        assert!((ratio - 4.48).abs() < 0.01);
+        assert_eq!(level(grey, LinearRgb::WHITE, TextSize::Normal), None);
+        assert_eq!(
+            level(grey, LinearRgb::WHITE, TextSize::Large),
+            Some(Level::Aa),
+        );
+    }
+}
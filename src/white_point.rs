@@ -0,0 +1,21 @@
+use std::fmt::Debug;
+
+/// A reference white, such as one of the standard [illuminants](crate::illuminant).
+///
+/// White points are used at the type level (as in [`Xyz<D50>`](crate::Xyz)),
+/// so they are usually unit structs.
+pub trait WhitePoint: Debug + Clone + Copy + PartialEq + PartialOrd {
+    /// The XYZ tristimulus values of the white, normalized so that Y is 1.
+    const XYZ: [f64; 3];
+
+    /// The chromaticity of the white.
+    const CHROMATICITY: crate::Chromaticity = {
+        let [x, y, z] = Self::XYZ;
+        let sum = x + y + z;
+
+        crate::Chromaticity {
+            x: x / sum,
+            y: y / sum,
+        }
+    };
+}
@@ -1,45 +1,80 @@
-/// A color from the CIE 1931 XYZ color space.
+use crate::float::const_from_f64;
+use std::marker::PhantomData;
+
+/// A color from the CIE 1931 XYZ color space, relative to the white point `W`.
 ///
-/// It is assumed that the color’s illuminant and observer are the standard D65 and 2-degree.
+/// The observer is assumed to be the standard 2-degree observer.
+/// By default colors are relative to [D65](crate::illuminant::D65);
+/// use [`adapt`](Xyz::adapt) to move a color to a different white point.
 #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
-pub struct Xyz {
+#[repr(C)]
+pub struct Xyz<W = crate::illuminant::D65, T = f32> {
     /// A mixture of cone cell response curves chosen by the CIE to be nonnegative.
-    /// Ranges from 0 to 0.95047003.
-    pub x: f32,
+    /// Ranges from 0 to the X of the white point.
+    pub x: T,
     /// Lightness of the color.
     /// 0 is complete black, 1 is the brightest white.
-    pub y: f32,
+    pub y: T,
     /// Roughly a measure of the blueness of the color.
-    /// Ranges from 0 (no blue) to 1.08883 (maxiumum blue).
-    pub z: f32,
+    /// Ranges from 0 (no blue) to the Z of the white point (maxiumum blue).
+    pub z: T,
+    white_point: PhantomData<W>,
+}
+
+impl<W, T> Xyz<W, T> {
+    /// Creates a new color from its components.
+    pub const fn new(x: T, y: T, z: T) -> Self {
+        Self {
+            x,
+            y,
+            z,
+            white_point: PhantomData,
+        }
+    }
 }
 
-impl crate::ColorSpace for Xyz {
-    const BLACK: Self = Self {
-        x: 0.0,
-        y: 0.0,
-        z: 0.0,
-    };
+impl<W: crate::WhitePoint, T: crate::Float> Xyz<W, T> {
+    /// Adapts the color to the white point `D` using the Bradford transform.
+    ///
+    /// ```
+    /// use tincture::illuminant::{D50, D65};
+    /// use tincture::{ColorSpace, Xyz};
+    ///
+    /// let adapted: Xyz<D50> = Xyz::<D65>::WHITE.adapt();
+    ///
+    /// assert!((adapted.x - Xyz::<D50>::WHITE.x).abs() < 0.0001);
+    /// assert!((adapted.z - Xyz::<D50>::WHITE.z).abs() < 0.0001);
+    /// ```
+    pub fn adapt<D: crate::WhitePoint>(self) -> Xyz<D, T> {
+        crate::ChromaticAdaptation::Bradford.adapt(self)
+    }
+}
 
-    const WHITE: Self = Self {
-        x: 0.95047003,
-        y: 1.0,
-        z: 0.108883,
-    };
+impl<W: crate::WhitePoint, T: crate::Float> crate::ColorSpace for Xyz<W, T> {
+    const BLACK: Self = Self::new(T::ZERO, T::ZERO, T::ZERO);
+
+    const WHITE: Self = Self::new(
+        const_from_f64(W::XYZ[0]),
+        const_from_f64(W::XYZ[1]),
+        const_from_f64(W::XYZ[2]),
+    );
 
     fn in_bounds(self) -> bool {
-        crate::approx_in_range(self.x, 0.0..0.95047003)
-            && crate::approx_in_range(self.y, 0.0..1.0)
-            && crate::approx_in_range(self.z, 0.0..1.08883)
+        crate::approx_in_range(self.x, T::ZERO..T::from_f64(W::XYZ[0]))
+            && crate::approx_in_range(self.y, T::ZERO..T::from_f64(W::XYZ[1]))
+            && crate::approx_in_range(self.z, T::ZERO..T::from_f64(W::XYZ[2]))
     }
 }
 
-impl crate::CoreColorSpace for Xyz {
-    fn from_xyz(xyz: Xyz) -> Self {
+impl<W: crate::WhitePoint, T: crate::Float> crate::CoreColorSpace for Xyz<W, T> {
+    type WhitePoint = W;
+    type Component = T;
+
+    fn from_xyz(xyz: Xyz<W, T>) -> Self {
         xyz
     }
 
-    fn to_xyz(self) -> Xyz {
+    fn to_xyz(self) -> Xyz<W, T> {
         self
     }
 }